@@ -159,7 +159,7 @@ export default function ClubDetailPage() {
             {proposals.map((proposal) => (
               <div key={proposal.id} className="bg-white p-6 rounded-lg shadow">
                 <h3 className="text-xl font-semibold mb-2">{proposal.title}</h3>
-                <p className="text-gray-600 mb-4">{proposal.description}</p>
+                <p className="text-gray-600 mb-4">{proposal.summary}</p>
                 <div className="flex justify-between text-sm text-gray-500">
                   <span>Status: {proposal.status}</span>
                   <span>
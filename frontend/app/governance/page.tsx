@@ -77,7 +77,7 @@ export default function GovernancePage() {
           {proposals.map((proposal) => (
             <div key={proposal.id} className="bg-white p-6 rounded-lg shadow">
               <h3 className="text-xl font-semibold mb-2">{proposal.title}</h3>
-              <p className="text-gray-600 mb-4">{proposal.description}</p>
+              <p className="text-gray-600 mb-4">{proposal.summary}</p>
               <div className="flex justify-between items-center">
                 <div className="text-sm text-gray-500">
                   <p>Status: {proposal.status}</p>
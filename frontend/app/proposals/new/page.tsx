@@ -4,6 +4,7 @@ import { useState, useEffect } from "react";
 import { useSearchParams, useRouter } from "next/navigation";
 import {
   proposalService,
+  MimeTag,
   ProposalType,
   VotingMechanism,
 } from "../../../lib/services/proposalService";
@@ -16,7 +17,10 @@ export default function NewProposalPage() {
 
   const [formData, setFormData] = useState({
     title: "",
-    description: "",
+    summary: "",
+    contentCid: "",
+    contentSize: 0,
+    contentMime: MimeTag.Markdown,
     proposalType: ProposalType.Operational,
     votingMechanism: VotingMechanism.SimpleMajority,
     votingDuration: 10000,
@@ -39,7 +43,14 @@ export default function NewProposalPage() {
         formData.proposalType,
         formData.votingMechanism,
         formData.title,
-        formData.description,
+        formData.summary,
+        formData.contentCid
+          ? {
+              cid: formData.contentCid,
+              size: formData.contentSize,
+              mime: formData.contentMime,
+            }
+          : null,
         formData.votingDuration,
         formData.approvalThreshold
       );
@@ -83,18 +94,77 @@ export default function NewProposalPage() {
         </div>
 
         <div className="mb-4">
-          <label className="block text-sm font-medium mb-2">Description</label>
+          <label className="block text-sm font-medium mb-2">Summary</label>
           <textarea
-            value={formData.description}
+            value={formData.summary}
             onChange={(e) =>
-              setFormData({ ...formData, description: e.target.value })
+              setFormData({ ...formData, summary: e.target.value })
             }
             className="w-full px-4 py-2 border rounded"
-            rows={6}
+            rows={3}
+            maxLength={256}
             required
           />
         </div>
 
+        <div className="mb-4">
+          <label className="block text-sm font-medium mb-2">
+            Full Proposal (IPFS CID)
+          </label>
+          <input
+            type="text"
+            value={formData.contentCid}
+            onChange={(e) =>
+              setFormData({ ...formData, contentCid: e.target.value.trim() })
+            }
+            className="w-full px-4 py-2 border rounded"
+            maxLength={64}
+            placeholder="Optional"
+          />
+        </div>
+
+        {formData.contentCid && (
+          <div className="mb-4 flex gap-4">
+            <div className="flex-1">
+              <label className="block text-sm font-medium mb-2">
+                Size (bytes)
+              </label>
+              <input
+                type="number"
+                value={formData.contentSize}
+                onChange={(e) =>
+                  setFormData({
+                    ...formData,
+                    contentSize: parseInt(e.target.value) || 0,
+                  })
+                }
+                className="w-full px-4 py-2 border rounded"
+                min="0"
+                required
+              />
+            </div>
+            <div className="flex-1">
+              <label className="block text-sm font-medium mb-2">Format</label>
+              <select
+                value={formData.contentMime}
+                onChange={(e) =>
+                  setFormData({
+                    ...formData,
+                    contentMime: e.target.value as MimeTag,
+                  })
+                }
+                className="w-full px-4 py-2 border rounded"
+              >
+                {Object.values(MimeTag).map((mime) => (
+                  <option key={mime} value={mime}>
+                    {mime}
+                  </option>
+                ))}
+              </select>
+            </div>
+          </div>
+        )}
+
         <div className="mb-4">
           <label className="block text-sm font-medium mb-2">
             Proposal Type
@@ -21,6 +21,22 @@ export enum VoteChoice {
   Abstain = "Abstain",
 }
 
+export enum MimeTag {
+  PlainText = "PlainText",
+  Markdown = "Markdown",
+  Html = "Html",
+  Json = "Json",
+  Pdf = "Pdf",
+  Image = "Image",
+  Other = "Other",
+}
+
+export interface ContentRef {
+  cid: string;
+  size: number;
+  mime: MimeTag;
+}
+
 export interface Proposal {
   id: number;
   clubId: number;
@@ -28,7 +44,8 @@ export interface Proposal {
   proposalType: ProposalType;
   votingMechanism: VotingMechanism;
   title: string;
-  description: string;
+  summary: string;
+  content: ContentRef | null;
   deposit: string;
   createdAt: number;
   votingEnd: number;
@@ -37,6 +54,10 @@ export interface Proposal {
   nayVotes: string;
   abstainVotes: string;
   approvalThreshold: number;
+  passing: boolean;
+  decided: boolean;
+  eligibleMembers: number;
+  eligiblePower: string;
 }
 
 export class ProposalService {
@@ -54,7 +75,8 @@ export class ProposalService {
     proposalType: ProposalType,
     votingMechanism: VotingMechanism,
     title: string,
-    description: string,
+    summary: string,
+    content: ContentRef | null,
     votingDuration: number,
     approvalThreshold: number
   ): Promise<string> {
@@ -64,7 +86,14 @@ export class ProposalService {
       { [proposalType]: null },
       { [votingMechanism]: null },
       new TextEncoder().encode(title),
-      new TextEncoder().encode(description),
+      new TextEncoder().encode(summary),
+      content
+        ? {
+            cid: new TextEncoder().encode(content.cid),
+            size: content.size,
+            mime: { [content.mime]: null },
+          }
+        : null,
       votingDuration,
       approvalThreshold
     );
@@ -89,58 +118,97 @@ export class ProposalService {
     return tx.hash.toString();
   }
 
-  async getProposals(clubId: number): Promise<Proposal[]> {
+  // `call` is an encoded call, e.g. from `encodeDistributeReturns`, that the
+  // proposal dispatches on behalf of the club once it passes
+  async setProposalAction(
+    clubId: number,
+    proposalId: number,
+    call: string
+  ): Promise<string> {
     const api = await this.getApi();
-    const proposalCount = await api.query.ikubGovernance.proposalCount(clubId);
-    const count = proposalCount.toNumber();
-
-    const proposals: Proposal[] = [];
-    for (let i = 0; i < count; i++) {
-      const proposal = await api.query.ikubGovernance.proposals(clubId, i);
-      if (proposal.isSome) {
-        const propData = proposal.unwrap();
-        proposals.push({
-          id: i,
-          clubId,
-          proposer: propData.proposer.toString(),
-          proposalType: this.decodeProposalType(propData.proposalType),
-          votingMechanism: this.decodeVotingMechanism(propData.votingMechanism),
-          title: new TextDecoder().decode(propData.title),
-          description: new TextDecoder().decode(propData.description),
-          deposit: propData.deposit.toString(),
-          createdAt: propData.createdAt.toNumber(),
-          votingEnd: propData.votingEnd.toNumber(),
-          status: this.decodeStatus(propData.status),
-          ayeVotes: propData.ayeVotes.toString(),
-          nayVotes: propData.nayVotes.toString(),
-          abstainVotes: propData.abstainVotes.toString(),
-          approvalThreshold: propData.approvalThreshold,
-        });
-      }
-    }
-    return proposals;
+    const tx = api.tx.ikubGovernance.setProposalAction(
+      clubId,
+      proposalId,
+      call
+    );
+    return tx.hash.toString();
+  }
+
+  async getProposals(
+    clubId: number,
+    status: string | null = null,
+    offset = 0,
+    limit = 100
+  ): Promise<Proposal[]> {
+    const api = await this.getApi();
+    const proposals: any[] = await api.provider.send(
+      "ikubGovernance_proposals",
+      [clubId, status, offset, limit]
+    );
+    return proposals.map((p) => this.fromRpc(p));
   }
 
-  private decodeProposalType(type: any): ProposalType {
-    if (type.isInvestment) return ProposalType.Investment;
-    if (type.isOperational) return ProposalType.Operational;
-    if (type.isEmergency) return ProposalType.Emergency;
-    return ProposalType.Constitutional;
+  async getProposal(
+    clubId: number,
+    proposalId: number
+  ): Promise<Proposal | null> {
+    const api = await this.getApi();
+    const proposal = await api.provider.send("ikubGovernance_proposal", [
+      clubId,
+      proposalId,
+    ]);
+    return proposal ? this.fromRpc(proposal) : null;
+  }
+
+  async getVotingPower(
+    clubId: number,
+    proposalId: number,
+    account: string
+  ): Promise<string> {
+    const api = await this.getApi();
+    const power = await api.provider.send("ikubGovernance_votingPower", [
+      clubId,
+      proposalId,
+      account,
+    ]);
+    return this.balanceFromRpc(power);
   }
 
-  private decodeVotingMechanism(mechanism: any): VotingMechanism {
-    if (mechanism.isSimpleMajority) return VotingMechanism.SimpleMajority;
-    if (mechanism.isQuadratic) return VotingMechanism.Quadratic;
-    if (mechanism.isConviction) return VotingMechanism.Conviction;
-    return VotingMechanism.Delegated;
+  // Balances arrive as a JSON number, or as a hex string once they no
+  // longer fit in one
+  private balanceFromRpc(value: number | string): string {
+    return BigInt(value).toString();
   }
 
-  private decodeStatus(status: any): string {
-    if (status.isActive) return "Active";
-    if (status.isPassed) return "Passed";
-    if (status.isRejected) return "Rejected";
-    if (status.isExpired) return "Expired";
-    return "Cancelled";
+  private fromRpc(p: any): Proposal {
+    return {
+      id: p.id,
+      clubId: p.club_id,
+      proposer: p.proposer,
+      proposalType: p.proposal_type as ProposalType,
+      votingMechanism: p.voting_mechanism as VotingMechanism,
+      title: new TextDecoder().decode(new Uint8Array(p.title)),
+      summary: new TextDecoder().decode(new Uint8Array(p.summary)),
+      content: p.content
+        ? {
+            cid: new TextDecoder().decode(new Uint8Array(p.content.cid)),
+            size: p.content.size,
+            mime: p.content.mime as MimeTag,
+          }
+        : null,
+      deposit: this.balanceFromRpc(p.deposit),
+      createdAt: p.created_at,
+      votingEnd: p.voting_end,
+      status: p.status,
+      ayeVotes: this.balanceFromRpc(p.tally.aye_votes),
+      nayVotes: this.balanceFromRpc(p.tally.nay_votes),
+      abstainVotes: this.balanceFromRpc(p.tally.abstain_votes),
+      approvalThreshold: p.approval_threshold,
+      passing: p.tally.passing,
+      decided: p.tally.decided,
+      eligibleMembers: p.tally.eligible_members,
+      eligiblePower: this.balanceFromRpc(p.tally.eligible_power),
+    };
   }
 }
 
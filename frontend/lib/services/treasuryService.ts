@@ -60,14 +60,15 @@ export class TreasuryService {
     return tx.hash.toString();
   }
 
-  async distributeReturns(
-    clubId: number,
+  // Returns are distributed by a club's governance: this returns the
+  // encoded call to attach to a proposal as its action.
+  async encodeDistributeReturns(
     cycleId: number,
     returns: string
   ): Promise<string> {
     const api = await this.getApi();
-    const tx = api.tx.ikubTreasury.distributeReturns(clubId, cycleId, returns);
-    return tx.hash.toString();
+    const tx = api.tx.ikubTreasury.distributeReturns(cycleId, returns);
+    return tx.method.toHex();
   }
 
   async claimReturns(clubId: number, cycleId: number): Promise<string> {
@@ -113,6 +114,7 @@ export class TreasuryService {
   private decodeStatus(status: any): string {
     if (status.isOpen) return "Open";
     if (status.isClosed) return "Closed";
+    if (status.isSwept) return "Swept";
     return "Distributed";
   }
 }
@@ -22,34 +22,32 @@ export class XcmService {
     return this.api;
   }
 
-  async sendFundsToParachain(
-    clubId: number,
+  // Cross-chain calls are dispatched by a club's governance: these return
+  // the encoded call to attach to a proposal as its action.
+  async encodeSendFundsToParachain(
     destParaId: number,
     amount: string,
     beneficiary: string
   ): Promise<string> {
     const api = await this.getApi();
     const tx = api.tx.ikubCrosschain.sendFundsToParachain(
-      clubId,
       destParaId,
       amount,
       new TextEncoder().encode(beneficiary)
     );
-    return tx.hash.toString();
+    return tx.method.toHex();
   }
 
-  async executeRemoteInvestment(
-    clubId: number,
+  async encodeExecuteRemoteInvestment(
     destParaId: number,
     callData: string
   ): Promise<string> {
     const api = await this.getApi();
     const tx = api.tx.ikubCrosschain.executeRemoteInvestment(
-      clubId,
       destParaId,
       new TextEncoder().encode(callData)
     );
-    return tx.hash.toString();
+    return tx.method.toHex();
   }
 
   async getOperations(clubId: number): Promise<CrossChainOperation[]> {
@@ -6,12 +6,14 @@ pub use pallet::*;
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
-        traits::Currency,
+        traits::{Currency, ExistenceRequirement, WithdrawReasons},
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
     use scale_info::TypeInfo;
     use codec::{Decode, Encode, MaxEncodedLen};
     use sp_runtime::traits::{AccountIdConversion, SaturatedConversion};
+    use pallet_ikub_treasury::ClubTreasury;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -19,6 +21,19 @@ pub mod pallet {
         
         /// Currency type for cross-chain transfers
         type Currency: Currency<Self::AccountId>;
+        
+        /// Club treasuries that cross-chain transfers are paid from
+        type Treasury: ClubTreasury<Self::AccountId, Balance = BalanceOf<Self>>;
+        
+        /// Origin of a club acting through its governance, used to move its funds across chains
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ClubId>;
+        
+        /// Origin reporting whether funds in transit reached their destination
+        type OperatorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        
+        /// Pallet ID of the account holding funds in transit to other chains
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
     }
 
     #[pallet::pallet]
@@ -118,30 +133,26 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Send funds to another parachain via XCM
+        /// Send funds of the club whose governance dispatches this call to
+        /// another parachain via XCM
+        ///
+        /// The amount is charged against the club's spending limits.
         #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
         #[pallet::call_index(0)]
         pub fn send_funds_to_parachain(
             origin: OriginFor<T>,
-            club_id: ClubId,
             dest_para_id: u32,
             amount: BalanceOf<T>,
             beneficiary: Vec<u8>, // Account ID on destination chain
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
             
             ensure!(
                 dest_para_id > 0,
                 Error::<T>::InvalidParachainId
             );
             
-            // Get treasury account for club (computed from club_id)
-            // For MVP, we'll use a simplified approach - in production, integrate with treasury pallet
-            use sp_runtime::traits::AccountIdConversion;
-            use sp_runtime::ModuleId;
-            let module_id = ModuleId(*b"ikubtrsy");
-            let treasury_account: T::AccountId = module_id.into_account_truncating(&club_id.encode());
-            let balance = T::Currency::free_balance(&treasury_account);
+            let balance = T::Treasury::balance_of(club_id);
             ensure!(
                 balance >= amount,
                 Error::<T>::InsufficientBalance
@@ -153,12 +164,16 @@ pub mod pallet {
             OperationCount::<T>::insert(club_id, new_count);
             
             let now = <frame_system::Pallet<T>>::block_number();
+            // For MVP, we'll simulate the XCM execution
+            // In production, this would construct and send proper XCM v3 messages.
+            // Funds are parked in the pallet account while in transit until the
+            // operator reports the transfer as completed or failed
             let operation = CrossChainOperation {
                 id: operation_id,
                 club_id,
                 destination_parachain_id: dest_para_id,
                 operation_type: OperationType::TransferFunds,
-                status: OperationStatus::Pending,
+                status: OperationStatus::InProgress,
                 amount: Some(amount),
                 created_at: now,
                 completed_at: None,
@@ -166,22 +181,7 @@ pub mod pallet {
             
             CrossChainOperations::<T>::insert(club_id, operation_id, &operation);
             
-            // For MVP, we'll simulate the XCM execution
-            // In production, this would construct and send proper XCM v3 messages
-            // For now, we'll mark it as completed immediately (simulation)
-            let mut operation = operation;
-            operation.status = OperationStatus::Completed;
-            operation.completed_at = Some(now);
-            CrossChainOperations::<T>::insert(club_id, operation_id, &operation);
-            
-            // Transfer funds from treasury (in real implementation, XCM would handle this)
-            // For MVP simulation, we'll just deduct from treasury
-            T::Currency::withdraw(
-                &treasury_account,
-                amount,
-                frame_support::traits::WithdrawReasons::TRANSFER,
-                frame_support::traits::ExistenceRequirement::AllowDeath,
-            )?;
+            T::Treasury::withdraw_to(club_id, &Self::account_id(), amount)?;
             
             Self::deposit_event(Event::CrossChainOperationInitiated {
                 club_id,
@@ -196,24 +196,19 @@ pub mod pallet {
                 amount,
             });
             
-            Self::deposit_event(Event::CrossChainOperationCompleted {
-                club_id,
-                operation_id,
-            });
-            
             Ok(())
         }
 
-        /// Execute a remote investment call on another parachain
+        /// Execute a remote investment call on another parachain for the club
+        /// whose governance dispatches this call
         #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
         #[pallet::call_index(1)]
         pub fn execute_remote_investment(
             origin: OriginFor<T>,
-            club_id: ClubId,
             dest_para_id: u32,
             call_data: Vec<u8>, // Encoded call to execute on destination
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
             
             ensure!(
                 dest_para_id > 0,
@@ -266,6 +261,84 @@ pub mod pallet {
             
             Ok(())
         }
+
+        /// Report that funds in transit reached their destination, removing
+        /// them from this chain
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 2))]
+        #[pallet::call_index(2)]
+        pub fn complete_transfer(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            operation_id: CrossChainOperationId,
+        ) -> DispatchResult {
+            T::OperatorOrigin::ensure_origin(origin)?;
+            
+            let (mut operation, amount) = Self::transfer_in_progress(club_id, operation_id)?;
+            
+            let _ = T::Currency::withdraw(
+                &Self::account_id(),
+                amount,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            
+            operation.status = OperationStatus::Completed;
+            operation.completed_at = Some(<frame_system::Pallet<T>>::block_number());
+            CrossChainOperations::<T>::insert(club_id, operation_id, &operation);
+            
+            Self::deposit_event(Event::CrossChainOperationCompleted {
+                club_id,
+                operation_id,
+            });
+            
+            Ok(())
+        }
+
+        /// Report that funds in transit did not reach their destination,
+        /// returning them to the club treasury
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3, 3))]
+        #[pallet::call_index(3)]
+        pub fn fail_transfer(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            operation_id: CrossChainOperationId,
+        ) -> DispatchResult {
+            T::OperatorOrigin::ensure_origin(origin)?;
+            
+            let (mut operation, amount) = Self::transfer_in_progress(club_id, operation_id)?;
+            
+            T::Treasury::deposit_from(club_id, &Self::account_id(), amount)?;
+            
+            operation.status = OperationStatus::Failed;
+            operation.completed_at = Some(<frame_system::Pallet<T>>::block_number());
+            CrossChainOperations::<T>::insert(club_id, operation_id, &operation);
+            
+            Self::deposit_event(Event::CrossChainOperationFailed {
+                club_id,
+                operation_id,
+            });
+            
+            Ok(())
+        }
     }
-}
 
+    impl<T: Config> Pallet<T> {
+        /// Account holding funds in transit to other chains
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// A fund transfer still in transit and the amount it moves
+        fn transfer_in_progress(
+            club_id: ClubId,
+            operation_id: CrossChainOperationId,
+        ) -> Result<(CrossChainOperation<T>, BalanceOf<T>), DispatchError> {
+            let operation = Self::operations(club_id, operation_id)
+                .ok_or(Error::<T>::OperationNotFound)?;
+            match (&operation.operation_type, &operation.status, operation.amount) {
+                (OperationType::TransferFunds, OperationStatus::InProgress, Some(amount)) => Ok((operation, amount)),
+                _ => Err(Error::<T>::InvalidOperation.into()),
+            }
+        }
+    }
+}
@@ -2,20 +2,27 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use scale_info::TypeInfo;
     use codec::{Decode, Encode, MaxEncodedLen};
+    pub use pallet_ikub_governance::ContentRef;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     pub type ClubId = u64;
@@ -44,7 +51,8 @@ pub mod pallet {
         pub club_id: ClubId,
         pub initiator: T::AccountId,
         pub subject: T::AccountId,
-        pub description: BoundedVec<u8, ConstU32<1024>>,
+        pub summary: BoundedVec<u8, ConstU32<256>>,
+        pub content: Option<ContentRef>,
         pub status: DisputeStatus,
         pub created_at: BlockNumberFor<T>,
         pub resolved_at: Option<BlockNumberFor<T>>,
@@ -55,9 +63,10 @@ pub mod pallet {
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
-    pub struct Evidence<T: Config> {
+    pub struct EvidenceRecord<T: Config> {
         pub submitter: T::AccountId,
-        pub description: BoundedVec<u8, ConstU32<2048>>,
+        pub summary: BoundedVec<u8, ConstU32<256>>,
+        pub content: Option<ContentRef>,
         pub submitted_at: BlockNumberFor<T>,
     }
 
@@ -102,7 +111,7 @@ pub mod pallet {
             NMapKey<Blake2_128Concat, DisputeId>,
             NMapKey<Blake2_128Concat, T::AccountId>,
         ),
-        Evidence<T>,
+        EvidenceRecord<T>,
         OptionQuery,
     >;
 
@@ -166,7 +175,8 @@ pub mod pallet {
             origin: OriginFor<T>,
             club_id: ClubId,
             subject: T::AccountId,
-            description: Vec<u8>,
+            summary: Vec<u8>,
+            content: Option<ContentRef>,
         ) -> DispatchResult {
             let initiator = ensure_signed(origin)?;
             
@@ -180,8 +190,9 @@ pub mod pallet {
                 club_id,
                 initiator: initiator.clone(),
                 subject: subject.clone(),
-                description: BoundedVec::try_from(description)
+                summary: BoundedVec::try_from(summary)
                     .map_err(|_| Error::<T>::InvalidDispute)?,
+                content,
                 status: DisputeStatus::Open,
                 created_at: now,
                 resolved_at: None,
@@ -209,7 +220,8 @@ pub mod pallet {
             origin: OriginFor<T>,
             club_id: ClubId,
             dispute_id: DisputeId,
-            evidence_description: Vec<u8>,
+            summary: Vec<u8>,
+            content: Option<ContentRef>,
         ) -> DispatchResult {
             let submitter = ensure_signed(origin)?;
             
@@ -227,10 +239,11 @@ pub mod pallet {
             );
             
             let now = <frame_system::Pallet<T>>::block_number();
-            let evidence = Evidence {
+            let evidence = EvidenceRecord {
                 submitter: submitter.clone(),
-                description: BoundedVec::try_from(evidence_description)
+                summary: BoundedVec::try_from(summary)
                     .map_err(|_| Error::<T>::InvalidDispute)?,
+                content,
                 submitted_at: now,
             };
             
@@ -0,0 +1,84 @@
+//! Storage migrations for the disputes pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+    use pallet_ikub_governance::migrations::v1::note_body;
+
+    /// Dispute layout with the full description stored inline
+    #[derive(Decode)]
+    pub struct OldDispute<T: Config> {
+        pub id: DisputeId,
+        pub club_id: ClubId,
+        pub initiator: T::AccountId,
+        pub subject: T::AccountId,
+        pub description: BoundedVec<u8, ConstU32<1024>>,
+        pub status: DisputeStatus,
+        pub created_at: BlockNumberFor<T>,
+        pub resolved_at: Option<BlockNumberFor<T>>,
+        pub favor_initiator_votes: u32,
+        pub favor_subject_votes: u32,
+        pub abstain_votes: u32,
+    }
+
+    /// Evidence layout with the full description stored inline
+    #[derive(Decode)]
+    pub struct OldEvidence<T: Config> {
+        pub submitter: T::AccountId,
+        pub description: BoundedVec<u8, ConstU32<2048>>,
+        pub submitted_at: BlockNumberFor<T>,
+    }
+
+    /// Moves inline dispute and evidence descriptions into the governance
+    /// note store, keeping their first 256 bytes as a summary
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config + pallet_ikub_governance::Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            Disputes::<T>::translate::<OldDispute<T>, _>(|_, _, old| {
+                translated += 1;
+                let (summary, content) = note_body::<T>(&old.initiator, old.description.into_inner());
+                Some(Dispute {
+                    id: old.id,
+                    club_id: old.club_id,
+                    initiator: old.initiator,
+                    subject: old.subject,
+                    summary,
+                    content,
+                    status: old.status,
+                    created_at: old.created_at,
+                    resolved_at: old.resolved_at,
+                    favor_initiator_votes: old.favor_initiator_votes,
+                    favor_subject_votes: old.favor_subject_votes,
+                    abstain_votes: old.abstain_votes,
+                })
+            });
+            Evidence::<T>::translate::<OldEvidence<T>, _>(|_, old| {
+                translated += 1;
+                let (summary, content) = note_body::<T>(&old.submitter, old.description.into_inner());
+                Some(EvidenceRecord {
+                    submitter: old.submitter,
+                    summary,
+                    content,
+                    submitted_at: old.submitted_at,
+                })
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            // Each description is also looked up in and written to the note store
+            T::DbWeight::get().reads_writes(2 * translated + 1, 2 * translated + 1)
+        }
+    }
+}
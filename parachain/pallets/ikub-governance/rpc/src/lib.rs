@@ -0,0 +1,249 @@
+//! RPC interface for the IkubChain governance pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use pallet_ikub_governance::{
+    ClubId, ProposalComment, ProposalId, ProposalInfo, ProposalRevision, ProposalStatus, ProposalTally,
+    VoteInfo,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_ikub_governance_runtime_api::GovernanceApi as GovernanceRuntimeApi;
+
+/// Maximum number of proposals returned by a single `ikubGovernance_proposals` call
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Error code for failures inside the runtime call
+const RUNTIME_ERROR: i32 = 1;
+
+/// Governance queries of the runtime, with `Balance` the runtime's balance type
+///
+/// Balances are served as `NumberOrHex`, since JSON numbers cannot hold
+/// every `u128`.
+#[rpc(client, server)]
+pub trait GovernanceApi<BlockHash, AccountId, Balance, BlockNumber> {
+    /// Proposals of a club in creation order, optionally filtered by status
+    #[method(name = "ikubGovernance_proposals")]
+    fn proposals(
+        &self,
+        club_id: ClubId,
+        status: Option<ProposalStatus>,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId, NumberOrHex, BlockNumber>>>;
+
+    /// A single proposal with its current tally and projected outcome
+    #[method(name = "ikubGovernance_proposal")]
+    fn proposal(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId, NumberOrHex, BlockNumber>>>;
+
+    /// Votes cast by an account on the proposals of a club
+    #[method(name = "ikubGovernance_votesOf")]
+    fn votes_of(
+        &self,
+        club_id: ClubId,
+        voter: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VoteInfo<NumberOrHex, BlockNumber>>>;
+
+    /// Voting power an account has on a proposal, zero if not eligible
+    #[method(name = "ikubGovernance_votingPower")]
+    fn voting_power(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    /// All revisions of a proposal, oldest first
+    #[method(name = "ikubGovernance_proposalRevisions")]
+    fn proposal_revisions(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalRevision<AccountId, BlockNumber>>>;
+
+    /// All comments posted on a proposal, oldest first
+    #[method(name = "ikubGovernance_proposalComments")]
+    fn proposal_comments(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalComment<AccountId, BlockNumber>>>;
+}
+
+/// Governance RPC handler backed by the client's runtime API
+pub struct Governance<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Governance<C, Block> {
+    /// Create a new instance of the governance RPC handler
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Runtime error",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+fn proposal_into_rpc<AccountId, Balance: Into<NumberOrHex>, BlockNumber>(
+    info: ProposalInfo<AccountId, Balance, BlockNumber>,
+) -> ProposalInfo<AccountId, NumberOrHex, BlockNumber> {
+    ProposalInfo {
+        id: info.id,
+        club_id: info.club_id,
+        proposer: info.proposer,
+        proposal_type: info.proposal_type,
+        voting_mechanism: info.voting_mechanism,
+        title: info.title,
+        summary: info.summary,
+        content: info.content,
+        deposit: info.deposit.into(),
+        created_at: info.created_at,
+        voting_end: info.voting_end,
+        status: info.status,
+        approval_threshold: info.approval_threshold,
+        tally: ProposalTally {
+            aye_votes: info.tally.aye_votes.into(),
+            nay_votes: info.tally.nay_votes.into(),
+            abstain_votes: info.tally.abstain_votes.into(),
+            passing: info.tally.passing,
+            decided: info.tally.decided,
+            eligible_members: info.tally.eligible_members,
+            eligible_power: info.tally.eligible_power.into(),
+        },
+    }
+}
+
+fn vote_into_rpc<Balance: Into<NumberOrHex>, BlockNumber>(
+    vote: VoteInfo<Balance, BlockNumber>,
+) -> VoteInfo<NumberOrHex, BlockNumber> {
+    VoteInfo {
+        proposal_id: vote.proposal_id,
+        choice: vote.choice,
+        power: vote.power.into(),
+        cast_at: vote.cast_at,
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    GovernanceApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Governance<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: GovernanceRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn proposals(
+        &self,
+        club_id: ClubId,
+        status: Option<ProposalStatus>,
+        offset: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId, NumberOrHex, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .proposals(at, club_id, status, offset, limit.min(MAX_PAGE_SIZE))
+            .map(|proposals| proposals.into_iter().map(proposal_into_rpc).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn proposal(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId, NumberOrHex, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .proposal(at, club_id, proposal_id)
+            .map(|proposal| proposal.map(proposal_into_rpc))
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn votes_of(
+        &self,
+        club_id: ClubId,
+        voter: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VoteInfo<NumberOrHex, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .votes_of(at, club_id, voter)
+            .map(|votes| votes.into_iter().map(vote_into_rpc).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn voting_power(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .voting_power(at, club_id, proposal_id, who)
+            .map(Into::into)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn proposal_revisions(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProposalRevision<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .proposal_revisions(at, club_id, proposal_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn proposal_comments(
+        &self,
+        club_id: ClubId,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProposalComment<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .proposal_comments(at, club_id, proposal_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
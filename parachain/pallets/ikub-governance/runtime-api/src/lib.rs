@@ -0,0 +1,42 @@
+//! Runtime API definition for the IkubChain governance pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_ikub_governance::{
+    ClubId, ProposalComment, ProposalId, ProposalInfo, ProposalRevision, ProposalStatus, VoteInfo,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Queries over club proposals
+    pub trait GovernanceApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Proposals of a club in creation order, optionally filtered by status
+        fn proposals(
+            club_id: ClubId,
+            status: Option<ProposalStatus>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ProposalInfo<AccountId, Balance, BlockNumber>>;
+
+        /// A single proposal with its current tally and projected outcome
+        fn proposal(club_id: ClubId, proposal_id: ProposalId) -> Option<ProposalInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Votes cast by an account on the proposals of a club
+        fn votes_of(club_id: ClubId, voter: AccountId) -> Vec<VoteInfo<Balance, BlockNumber>>;
+
+        /// Voting power an account has on a proposal, zero if not eligible
+        fn voting_power(club_id: ClubId, proposal_id: ProposalId, who: AccountId) -> Balance;
+
+        /// All revisions of a proposal, oldest first
+        fn proposal_revisions(club_id: ClubId, proposal_id: ProposalId) -> Vec<ProposalRevision<AccountId, BlockNumber>>;
+
+        /// All comments posted on a proposal, oldest first
+        fn proposal_comments(club_id: ClubId, proposal_id: ProposalId) -> Vec<ProposalComment<AccountId, BlockNumber>>;
+    }
+}
@@ -2,16 +2,30 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
+        dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
-        traits::{Currency, ReservableCurrency},
+        traits::{Currency, EnsureOrigin, ExistenceRequirement, ReservableCurrency, WithdrawReasons},
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero, SaturatedConversion};
+    use sp_runtime::traits::{AccountIdConversion, Dispatchable, Hash, One, Saturating, Zero, SaturatedConversion};
+    use sp_std::marker::PhantomData;
     use scale_info::TypeInfo;
     use codec::{Decode, Encode, MaxEncodedLen};
+    use pallet_ikub_members::ClubMembership;
+    use pallet_ikub_treasury::ClubTreasury;
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
@@ -33,10 +47,78 @@ pub mod pallet {
         /// Minimum proposal deposit
         #[pallet::constant]
         type MinProposalDeposit: Get<BalanceOf<Self>>;
+        
+        /// Source of club membership, used to determine the eligible electorate
+        type Members: ClubMembership<Self::AccountId, BlockNumberFor<Self>>;
+        
+        /// Maximum number of members of a club, bounding the electorate of a proposal
+        #[pallet::constant]
+        type MaxMembersPerClub: Get<u32>;
+        
+        /// The overarching origin type, able to represent a club acting through governance
+        type RuntimeOrigin: From<RawOrigin>;
+        
+        /// Calls that winning proposal options dispatch on behalf of their club
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as Config>::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
+        
+        /// Maximum number of options on a multi-option proposal
+        #[pallet::constant]
+        type MaxProposalOptions: Get<u32>;
+        
+        /// Percentage of the eligible voting power that must vote for a proposal to pass
+        #[pallet::constant]
+        type Quorum: Get<u8>;
+        
+        /// Club treasuries receiving governance penalties
+        type Treasury: ClubTreasury<Self::AccountId, Balance = BalanceOf<Self>>;
+        
+        /// Club funds moved by the calls proposals enact, deciding whether
+        /// guardians can veto them
+        type Spends: CallSpend<<Self as Config>::RuntimeCall, BalanceOf<Self>>;
+        
+        /// Origin of a club acting through its own governance
+        type ClubOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = ClubId>;
+        
+        /// Maximum number of guardians in a club's guardian council
+        #[pallet::constant]
+        type MaxGuardians: Get<u32>;
+        
+        /// Maximum number of comments per proposal
+        #[pallet::constant]
+        type MaxCommentsPerProposal: Get<u32>;
+        
+        /// Maximum size in bytes of a body kept in the note store
+        #[pallet::constant]
+        type MaxNoteSize: Get<u32>;
+        
+        /// Maximum number of proposals bundled in one governance session
+        #[pallet::constant]
+        type MaxProposalsPerSession: Get<u32>;
+        
+        /// Maximum number of governance sessions opening or closing in one block
+        #[pallet::constant]
+        type MaxSessionsPerBlock: Get<u32>;
+        
+        /// Base deposit for keeping a note
+        #[pallet::constant]
+        type NoteDepositBase: Get<BalanceOf<Self>>;
+        
+        /// Additional deposit per byte of a note
+        #[pallet::constant]
+        type NoteDepositPerByte: Get<BalanceOf<Self>>;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// Blocks probed for room when queueing a session opening or closing
+    const MAX_SLOT_PROBES: u32 = 10;
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Type alias for balance
@@ -47,9 +129,53 @@ pub mod pallet {
     
     /// Type alias for proposal ID
     pub type ProposalId = u64;
+    
+    /// Type alias for governance session ID
+    pub type SessionId = u64;
+    
+    /// Type alias for proposal revision number
+    pub type RevisionNumber = u32;
+    
+    /// Hash of an off-chain document (e.g. the digest of an IPFS CID)
+    pub type ContentHash = [u8; 32];
+    
+    /// Type alias for the index of an option on a multi-option proposal
+    pub type OptionIndex = u32;
+    
+    /// SCALE-encoded runtime call
+    pub type EncodedCall = BoundedVec<u8, ConstU32<1024>>;
+
+    /// Origin of calls dispatched by a club through its governance
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RawOrigin {
+        /// Action enacted by a passed proposal of the club
+        Club(ClubId),
+    }
+
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
+    /// Ensures a call was dispatched by a club's governance, yielding the club ID
+    pub struct EnsureClub<T>(PhantomData<T>);
+
+    impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>, T> EnsureOrigin<O> for EnsureClub<T> {
+        type Success = ClubId;
+
+        fn try_origin(o: O) -> Result<Self::Success, O> {
+            o.into().map(|origin| match origin {
+                RawOrigin::Club(club_id) => club_id,
+            })
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn try_successful_origin() -> Result<O, ()> {
+            Ok(O::from(RawOrigin::Club(Zero::zero())))
+        }
+    }
 
     /// Proposal types
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     #[scale_info(skip_type_params(T))]
     pub enum ProposalType {
         /// Investment proposal requiring due diligence
@@ -64,6 +190,7 @@ pub mod pallet {
 
     /// Voting mechanism types
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     #[scale_info(skip_type_params(T))]
     pub enum VotingMechanism {
         /// Simple majority voting
@@ -78,6 +205,7 @@ pub mod pallet {
 
     /// Vote choice
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     #[scale_info(skip_type_params(T))]
     pub enum VoteChoice {
         /// Vote in favor
@@ -90,6 +218,7 @@ pub mod pallet {
 
     /// Proposal status
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     #[scale_info(skip_type_params(T))]
     pub enum ProposalStatus {
         /// Proposal is active and accepting votes
@@ -102,6 +231,10 @@ pub mod pallet {
         Expired,
         /// Proposal has been cancelled
         Cancelled,
+        /// Proposal is waiting for the next governance session to open
+        Scheduled,
+        /// Proposal passed but was vetoed by a guardian before enactment
+        Vetoed,
     }
 
     /// Proposal structure
@@ -120,8 +253,10 @@ pub mod pallet {
         pub voting_mechanism: VotingMechanism,
         /// Proposal title
         pub title: BoundedVec<u8, ConstU32<256>>,
-        /// Proposal description
-        pub description: BoundedVec<u8, ConstU32<4096>>,
+        /// Short inline summary
+        pub summary: BoundedVec<u8, ConstU32<256>>,
+        /// Full proposal body, kept off-chain or in the note store
+        pub content: Option<ContentRef>,
         /// Deposit amount
         pub deposit: BalanceOf<T>,
         /// Block number when proposal was created
@@ -154,6 +289,284 @@ pub mod pallet {
         pub cast_at: BlockNumberFor<T>,
     }
 
+    /// Counting rule of a multi-option proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum TallyMethod {
+        /// Each voter picks one option; the option with the most power wins
+        Plurality,
+        /// Each voter approves any number of options, each receiving their full power
+        Approval,
+        /// Voters rank options; the weakest option is eliminated until one has a majority
+        InstantRunoff,
+    }
+
+    /// Candidate choice on a multi-option proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct ProposalOption {
+        /// Short label shown to voters
+        pub label: BoundedVec<u8, ConstU32<64>>,
+        /// Call dispatched with the club origin if this option wins
+        pub call: Option<EncodedCall>,
+    }
+
+    /// Options of a multi-option proposal and how they are tallied
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OptionBallot<T: Config> {
+        /// Counting rule
+        pub method: TallyMethod,
+        /// Candidate options, addressed by their index
+        pub options: BoundedVec<ProposalOption, T::MaxProposalOptions>,
+    }
+
+    /// Vote on a multi-option proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OptionVote<T: Config> {
+        /// Chosen option, approved options or ranking, depending on the tally method
+        pub selection: BoundedVec<OptionIndex, T::MaxProposalOptions>,
+        /// Voting power (weighted)
+        pub power: BalanceOf<T>,
+        /// Block number when vote was cast
+        pub cast_at: BlockNumberFor<T>,
+    }
+
+    /// Winning option of a passed multi-option proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct WinningOption {
+        /// Index of the winning option
+        pub index: OptionIndex,
+        /// Whether the option's call has been dispatched
+        pub enacted: bool,
+    }
+
+    /// Media type of referenced content
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum MimeTag {
+        PlainText,
+        Markdown,
+        Html,
+        Json,
+        Pdf,
+        Image,
+        Other,
+    }
+
+    /// Reference to a document kept off-chain or in the note store
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ContentRef {
+        /// Content identifier: an IPFS CID, or the hash of a note
+        pub cid: BoundedVec<u8, ConstU32<64>>,
+        /// Size of the content in bytes
+        pub size: u32,
+        /// Media type of the content
+        pub mime: MimeTag,
+    }
+
+    /// Small document body kept on-chain against a deposit
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Note<T: Config> {
+        /// Account that noted the body and holds the deposit
+        pub depositor: T::AccountId,
+        /// Reserved deposit
+        pub deposit: BalanceOf<T>,
+        /// The body itself
+        pub data: BoundedVec<u8, T::MaxNoteSize>,
+    }
+
+    /// Revision of a proposal body published by its proposer
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ProposalRevision<AccountId, BlockNumber> {
+        /// Revision number, starting at 1
+        pub version: RevisionNumber,
+        /// Hash of the revised off-chain body
+        pub content_hash: ContentHash,
+        /// Short summary of the changes from the previous revision
+        pub summary: BoundedVec<u8, ConstU32<256>>,
+        /// Revision author
+        pub author: AccountId,
+        /// Block number when the revision was published
+        pub created_at: BlockNumber,
+        /// Whether votes cast before this revision were discarded
+        pub votes_reset: bool,
+    }
+
+    /// Comment posted on a proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ProposalComment<AccountId, BlockNumber> {
+        /// Comment author
+        pub author: AccountId,
+        /// Hash of the off-chain comment body
+        pub content_hash: ContentHash,
+        /// Proposal revision the comment refers to
+        pub revision: RevisionNumber,
+        /// Block number when the comment was posted
+        pub posted_at: BlockNumber,
+    }
+
+    /// Recurring governance session schedule of a club
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct SessionSchedule<BlockNumber> {
+        /// Blocks between the openings of consecutive sessions
+        pub interval: BlockNumber,
+        /// Voting window shared by all proposals of a session
+        pub voting_period: BlockNumber,
+        /// Block at which the next session opens
+        pub next_session_at: BlockNumber,
+    }
+
+    /// Governance session status
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum SessionStatus {
+        /// Proposals of the session are open for voting
+        Voting,
+        /// All proposals of the session have been finalized
+        Finalized,
+    }
+
+    /// Governance session bundling proposals under a common voting window
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct GovernanceSession<T: Config> {
+        pub id: SessionId,
+        pub club_id: ClubId,
+        pub opened_at: BlockNumberFor<T>,
+        pub voting_end: BlockNumberFor<T>,
+        pub proposals: BoundedVec<ProposalId, T::MaxProposalsPerSession>,
+        pub status: SessionStatus,
+    }
+
+    /// Commit-reveal settings of a secret ballot
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct SecretBallot<BlockNumber, Balance> {
+        /// Blocks after `voting_end` during which commitments can be revealed
+        pub reveal_period: BlockNumber,
+        /// Bond reserved per commitment and slashed if it is never revealed
+        pub bond: Balance,
+    }
+
+    /// Sealed vote awaiting reveal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct VoteCommitment<Hash, Balance> {
+        /// Hash of the SCALE-encoded `(voter, proposal_id, choice, salt)` tuple
+        pub commitment: Hash,
+        /// Bond reserved from the voter
+        pub bond: Balance,
+    }
+
+    /// Guardians allowed to veto large investment proposals of a club
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct GuardianCouncil<T: Config> {
+        /// Elected members or external auditor accounts
+        pub guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+        /// Investment proposals moving at least this amount can be vetoed
+        pub min_amount: BalanceOf<T>,
+        /// Blocks after passage during which a guardian can veto
+        pub veto_period: BlockNumberFor<T>,
+        /// Length of the re-vote that can override a veto
+        pub override_period: BlockNumberFor<T>,
+        /// Percentage of aye votes a re-vote needs to override a veto
+        pub override_threshold: u8,
+    }
+
+    /// Action carried out when a proposal, or the winning option of a
+    /// multi-option proposal, is enacted
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProposalAction<T: Config> {
+        /// Amount of club funds the action moves, as reported by `Config::Spends`
+        pub amount: BalanceOf<T>,
+        /// Call dispatched with the club origin
+        pub call: EncodedCall,
+        /// Block from which the action can be enacted, set once the proposal passes
+        pub enactable_at: Option<BlockNumberFor<T>>,
+        /// Whether the action has been enacted
+        pub enacted: bool,
+    }
+
+    /// Guardian veto of a passed proposal
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Veto<AccountId, BlockNumber> {
+        /// Guardian who vetoed
+        pub guardian: AccountId,
+        /// Hash of the published reasoning
+        pub reason: ContentHash,
+        /// Block number when the veto was issued
+        pub vetoed_at: BlockNumber,
+        /// Whether a supermajority re-vote overrode the veto
+        pub overridden: bool,
+    }
+
+    /// Electorate of a proposal as of its creation
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct VoterSnapshot<BlockNumber, Balance> {
+        /// Members who joined after this block cannot vote
+        pub cutoff: BlockNumber,
+        /// Number of eligible members
+        pub eligible_members: u32,
+        /// Combined voting power of eligible members at creation
+        pub eligible_power: Balance,
+    }
+
+    /// Vote tally of a proposal with its projected outcome
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ProposalTally<Balance> {
+        /// Aye votes (weighted)
+        pub aye_votes: Balance,
+        /// Nay votes (weighted)
+        pub nay_votes: Balance,
+        /// Abstain votes (weighted)
+        pub abstain_votes: Balance,
+        /// Whether the proposal passes with the votes cast so far
+        pub passing: bool,
+        /// Whether the outstanding voting power can no longer change the outcome
+        pub decided: bool,
+        /// Number of members eligible to vote, zero once voting has closed
+        pub eligible_members: u32,
+        /// Combined voting power of eligible members as of the proposal's
+        /// creation, zero once voting has closed
+        pub eligible_power: Balance,
+    }
+
+    /// Proposal as served to clients through the runtime API
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ProposalInfo<AccountId, Balance, BlockNumber> {
+        pub id: ProposalId,
+        pub club_id: ClubId,
+        pub proposer: AccountId,
+        pub proposal_type: ProposalType,
+        pub voting_mechanism: VotingMechanism,
+        pub title: Vec<u8>,
+        pub summary: Vec<u8>,
+        pub content: Option<ContentRef>,
+        pub deposit: Balance,
+        pub created_at: BlockNumber,
+        pub voting_end: BlockNumber,
+        pub status: ProposalStatus,
+        pub approval_threshold: u8,
+        pub tally: ProposalTally<Balance>,
+    }
+
+    /// Vote as served to clients through the runtime API
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct VoteInfo<Balance, BlockNumber> {
+        pub proposal_id: ProposalId,
+        pub choice: VoteChoice,
+        pub power: Balance,
+        pub cast_at: BlockNumber,
+    }
+
     /// Storage: Active proposals by club
     #[pallet::storage]
     #[pallet::getter(fn proposals)]
@@ -203,118 +616,1673 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// A new proposal was created
-        ProposalCreated {
-            club_id: ClubId,
-            proposal_id: ProposalId,
-            proposer: T::AccountId,
-            proposal_type: ProposalType,
-        },
-        /// A vote was cast
-        VoteCast {
-            club_id: ClubId,
-            proposal_id: ProposalId,
-            voter: T::AccountId,
-            choice: VoteChoice,
-            power: BalanceOf<T>,
-        },
-        /// A proposal passed
-        ProposalPassed {
-            club_id: ClubId,
-            proposal_id: ProposalId,
-        },
-        /// A proposal was rejected
-        ProposalRejected {
-            club_id: ClubId,
-            proposal_id: ProposalId,
-        },
-        /// A proposal expired
-        ProposalExpired {
-            club_id: ClubId,
-            proposal_id: ProposalId,
-        },
-    }
+    /// Storage: Revisions for each proposal
+    #[pallet::storage]
+    pub type ProposalRevisions<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ProposalId>,
+            NMapKey<Blake2_128Concat, RevisionNumber>,
+        ),
+        ProposalRevision<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Proposal not found
-        ProposalNotFound,
-        /// Proposal is not in active state
-        ProposalNotActive,
-        /// Voting period has ended
-        VotingPeriodEnded,
-        /// Insufficient deposit
-        InsufficientDeposit,
-        /// Maximum proposals per club exceeded
-        MaxProposalsExceeded,
-        /// Invalid approval threshold
-        InvalidApprovalThreshold,
-        /// Already voted
-        AlreadyVoted,
-        /// Invalid voting duration
-        InvalidVotingDuration,
-    }
+    /// Storage: Latest revision number per proposal
+    #[pallet::storage]
+    #[pallet::getter(fn revision_count)]
+    pub type RevisionCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        RevisionNumber,
+        ValueQuery,
+    >;
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            // Check for expired proposals
-            // This would be implemented to check and expire proposals
-            Weight::zero()
-        }
-    }
+    /// Storage: Comments posted on each proposal
+    #[pallet::storage]
+    pub type ProposalComments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        BoundedVec<ProposalComment<T::AccountId, BlockNumberFor<T>>, T::MaxCommentsPerProposal>,
+        ValueQuery,
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Create a new proposal
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(0)]
-        pub fn create_proposal(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            proposal_type: ProposalType,
-            voting_mechanism: VotingMechanism,
-            title: Vec<u8>,
-            description: Vec<u8>,
-            voting_duration: BlockNumberFor<T>,
-            approval_threshold: u8,
-        ) -> DispatchResult {
-            let proposer = ensure_signed(origin)?;
-            
-            // Validate voting duration
-            ensure!(
-                voting_duration <= T::MaxVotingDuration::get(),
-                Error::<T>::InvalidVotingDuration
-            );
-            
-            // Validate approval threshold
-            ensure!(
-                approval_threshold > 0 && approval_threshold <= 100,
-                Error::<T>::InvalidApprovalThreshold
-            );
-            
-            // Check deposit requirement
-            let deposit = T::MinProposalDeposit::get();
-            T::Currency::reserve(&proposer, deposit)?;
-            
-            // Get next proposal ID
-            let proposal_id = Self::proposal_count(club_id);
-            let new_count = proposal_id.saturating_add(1);
-            ProposalCount::<T>::insert(club_id, new_count);
-            
-            // Check max proposals limit
-            let mut active = Self::active_proposals(club_id);
-            ensure!(
-                active.len() < T::MaxProposalsPerClub::get() as usize,
-                Error::<T>::MaxProposalsExceeded
-            );
+    /// Storage: Governance session schedule per club
+    #[pallet::storage]
+    #[pallet::getter(fn session_schedule)]
+    pub type SessionSchedules<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        SessionSchedule<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage: Proposals waiting for the next session of a club
+    #[pallet::storage]
+    #[pallet::getter(fn session_agenda)]
+    pub type SessionAgenda<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        BoundedVec<ProposalId, T::MaxProposalsPerSession>,
+        ValueQuery,
+    >;
+
+    /// Storage: Governance sessions per club
+    #[pallet::storage]
+    #[pallet::getter(fn sessions)]
+    pub type Sessions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        SessionId,
+        GovernanceSession<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Session counter per club
+    #[pallet::storage]
+    #[pallet::getter(fn session_count)]
+    pub type SessionCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        SessionId,
+        ValueQuery,
+    >;
+
+    /// Storage: Session a proposal is voted in
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_session)]
+    pub type ProposalSessions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        SessionId,
+        OptionQuery,
+    >;
+
+    /// Storage: Clubs whose next session opens at a block
+    #[pallet::storage]
+    pub type SessionOpenings<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClubId, T::MaxSessionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage: Sessions to finalize at a block
+    #[pallet::storage]
+    pub type SessionClosings<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(ClubId, SessionId), T::MaxSessionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage: Bond each club requires for secret ballot commitments
+    #[pallet::storage]
+    #[pallet::getter(fn secret_ballot_bond)]
+    pub type SecretBallotBonds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Proposals voted on by secret ballot
+    #[pallet::storage]
+    #[pallet::getter(fn secret_ballot)]
+    pub type SecretBallots<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        SecretBallot<BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage: Unrevealed vote commitments on secret ballots
+    #[pallet::storage]
+    #[pallet::getter(fn vote_commitments)]
+    pub type VoteCommitments<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ProposalId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        VoteCommitment<T::Hash, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage: Electorate snapshots of proposals that can still be voted on
+    #[pallet::storage]
+    #[pallet::getter(fn voter_snapshot)]
+    pub type VoterSnapshots<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        VoterSnapshot<BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage: Voting power of each eligible member of proposals that can
+    /// still be voted on, as of their creation
+    #[pallet::storage]
+    #[pallet::getter(fn voter_power)]
+    pub type VoterPowers<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ProposalId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Guardian councils of clubs
+    #[pallet::storage]
+    #[pallet::getter(fn guardian_council)]
+    pub type GuardianCouncils<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        GuardianCouncil<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Actions enacted by proposals once they pass
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_action)]
+    pub type ProposalActions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        ProposalAction<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Guardian vetoes of passed proposals
+    #[pallet::storage]
+    #[pallet::getter(fn veto)]
+    pub type Vetoes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        Veto<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage: Options of multi-option proposals
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_options)]
+    pub type ProposalOptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        OptionBallot<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Votes on multi-option proposals
+    #[pallet::storage]
+    #[pallet::getter(fn option_votes)]
+    pub type OptionVotes<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ProposalId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        OptionVote<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Power per option of multi-option proposals
+    ///
+    /// Holds first preferences for instant-runoff proposals while voting is
+    /// open and the final round once they are finalized.
+    #[pallet::storage]
+    #[pallet::getter(fn option_results)]
+    pub type OptionResults<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        BoundedVec<BalanceOf<T>, T::MaxProposalOptions>,
+        ValueQuery,
+    >;
+
+    /// Storage: Winning options of passed multi-option proposals
+    #[pallet::storage]
+    #[pallet::getter(fn winning_option)]
+    pub type WinningOptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ProposalId,
+        WinningOption,
+        OptionQuery,
+    >;
+
+    /// Storage: Note store for small document bodies, keyed by hash
+    #[pallet::storage]
+    #[pallet::getter(fn notes)]
+    pub type Notes<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::Hash,
+        Note<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new proposal was created
+        ProposalCreated {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            proposer: T::AccountId,
+            proposal_type: ProposalType,
+        },
+        /// A vote was cast
+        VoteCast {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            voter: T::AccountId,
+            choice: VoteChoice,
+            power: BalanceOf<T>,
+        },
+        /// A proposal passed
+        ProposalPassed {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        },
+        /// A proposal was rejected
+        ProposalRejected {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        },
+        /// A proposal expired
+        ProposalExpired {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        },
+        /// A proposal was finalized before its voting period ended because
+        /// the outstanding voting power could no longer change the outcome
+        ProposalFinalizedEarly {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            passed: bool,
+        },
+        /// A proposal was amended with a new revision
+        ProposalAmended {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            version: RevisionNumber,
+            votes_reset: bool,
+        },
+        /// A comment was posted on a proposal
+        CommentPosted {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            author: T::AccountId,
+            content_hash: ContentHash,
+        },
+        /// A club's governance session schedule was set
+        SessionScheduleSet {
+            club_id: ClubId,
+            interval: BlockNumberFor<T>,
+            voting_period: BlockNumberFor<T>,
+            next_session_at: BlockNumberFor<T>,
+        },
+        /// A club's governance session schedule was removed
+        SessionScheduleCleared {
+            club_id: ClubId,
+        },
+        /// A governance session opened voting on its bundled proposals
+        SessionOpened {
+            club_id: ClubId,
+            session_id: SessionId,
+            proposals: Vec<ProposalId>,
+            voting_end: BlockNumberFor<T>,
+        },
+        /// A governance session could not be queued for closing; its agenda
+        /// waits for the next session
+        SessionPostponed {
+            club_id: ClubId,
+        },
+        /// All proposals of a governance session were finalized
+        SessionFinalized {
+            club_id: ClubId,
+            session_id: SessionId,
+            passed: Vec<ProposalId>,
+            rejected: Vec<ProposalId>,
+            expired: Vec<ProposalId>,
+        },
+        /// A club set the bond required for secret ballot commitments
+        SecretBallotBondSet {
+            club_id: ClubId,
+            bond: BalanceOf<T>,
+        },
+        /// A proposal switched to commit-reveal voting
+        SecretBallotEnabled {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            reveal_period: BlockNumberFor<T>,
+        },
+        /// A sealed vote was committed
+        VoteCommitted {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            voter: T::AccountId,
+        },
+        /// A committed vote was never revealed and its bond went to the club treasury
+        UnrevealedVotePenalized {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            voter: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A vote was cast on a multi-option proposal
+        OptionVoteCast {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            voter: T::AccountId,
+            selection: Vec<OptionIndex>,
+            power: BalanceOf<T>,
+        },
+        /// A multi-option proposal passed with the given winning option
+        OptionSelected {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            option: OptionIndex,
+        },
+        /// The call of a winning option was dispatched
+        OptionEnacted {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            option: OptionIndex,
+            result: DispatchResult,
+        },
+        /// A club's guardian council was set through governance
+        GuardianCouncilSet {
+            club_id: ClubId,
+            guardians: Vec<T::AccountId>,
+        },
+        /// A club's guardian council was removed through governance
+        GuardianCouncilRemoved {
+            club_id: ClubId,
+        },
+        /// An action was attached to a proposal
+        ProposalActionSet {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            amount: BalanceOf<T>,
+        },
+        /// A guardian vetoed a passed proposal
+        ProposalVetoed {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            guardian: T::AccountId,
+            reason: ContentHash,
+        },
+        /// Members started a re-vote to override a veto
+        VetoOverrideStarted {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            voting_end: BlockNumberFor<T>,
+        },
+        /// A supermajority re-vote overrode a veto
+        VetoOverridden {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        },
+        /// The action of a passed proposal was dispatched
+        ProposalEnacted {
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            result: DispatchResult,
+        },
+        /// A document body was added to the note store
+        ContentNoted {
+            hash: T::Hash,
+            depositor: T::AccountId,
+            size: u32,
+        },
+        /// A document body was removed from the note store
+        ContentUnnoted {
+            hash: T::Hash,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Proposal not found
+        ProposalNotFound,
+        /// Proposal is not in active state
+        ProposalNotActive,
+        /// Voting period has ended
+        VotingPeriodEnded,
+        /// Insufficient deposit
+        InsufficientDeposit,
+        /// Maximum proposals per club exceeded
+        MaxProposalsExceeded,
+        /// Invalid approval threshold
+        InvalidApprovalThreshold,
+        /// Already voted
+        AlreadyVoted,
+        /// Invalid voting duration
+        InvalidVotingDuration,
+        /// Account is not a member of the club
+        NotClubMember,
+        /// Voting is still open and the outcome is not yet decided
+        OutcomeNotDecided,
+        /// Only the proposer can perform this action
+        NotProposer,
+        /// Votes have been cast and would have to be reset
+        VotesAlreadyCast,
+        /// Revision summary is too long
+        InvalidSummary,
+        /// Maximum comments per proposal exceeded
+        MaxCommentsExceeded,
+        /// Title is empty or too long
+        InvalidTitle,
+        /// Note is larger than `MaxNoteSize`
+        NoteTooLarge,
+        /// Note already exists
+        AlreadyNoted,
+        /// Note not found
+        NoteNotFound,
+        /// Only the depositor can remove a note
+        NotNoteDepositor,
+        /// Only the club administrator can perform this action
+        NotClubAdmin,
+        /// Session interval or voting period is invalid
+        InvalidSessionSchedule,
+        /// The club has no governance session schedule
+        NoSessionSchedule,
+        /// Too many proposals queued for the next session
+        SessionAgendaFull,
+        /// Too many sessions scheduled for the same block
+        TooManySessionsInBlock,
+        /// Proposal is finalized together with its governance session
+        ProposalInSession,
+        /// Club has more members than `MaxMembersPerClub` to snapshot
+        TooManyMembers,
+        /// Proposal is voted on by secret ballot
+        SecretBallot,
+        /// Proposal is not voted on by secret ballot
+        NotSecretBallot,
+        /// Vote already committed
+        AlreadyCommitted,
+        /// No vote commitment found
+        CommitmentNotFound,
+        /// Revealed vote does not match the commitment
+        InvalidReveal,
+        /// Secret ballot is not in its reveal phase
+        NotRevealPhase,
+        /// Secret ballot reveal phase has not ended yet
+        RevealPhaseNotEnded,
+        /// Proposal is voted on by choosing among options
+        MultiOptionProposal,
+        /// Proposal has no options
+        NotMultiOptionProposal,
+        /// Account was not a club member when the proposal was created
+        NotEligibleVoter,
+        /// A multi-option proposal needs between two and `MaxProposalOptions` options
+        InvalidOptionCount,
+        /// Option label is empty or too long
+        InvalidOptionLabel,
+        /// Option call is too large or cannot be decoded
+        InvalidOptionCall,
+        /// Selection is empty, repeats an option or does not fit the tally method
+        InvalidSelection,
+        /// Voting period has not ended yet
+        VotingPeriodNotEnded,
+        /// Proposal did not pass with a winning option
+        NoWinningOption,
+        /// Winning option has already been enacted
+        OptionAlreadyEnacted,
+        /// Winning option has no call to dispatch
+        OptionHasNoCall,
+        /// Guardian council is empty, too large or has an invalid threshold
+        InvalidGuardianCouncil,
+        /// Account is not a guardian of the club
+        NotGuardian,
+        /// Proposal has no action to enact
+        NoProposalAction,
+        /// Proposal cannot be vetoed or its veto period has ended
+        NotVetoable,
+        /// Proposal has not been vetoed
+        ProposalNotVetoed,
+        /// Proposal has not passed or its veto period is still running
+        EnactmentNotDue,
+        /// Proposal action has already been enacted
+        AlreadyEnacted,
+        /// The call to enact weighs more than the given weight bound
+        WrongWeightBound,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(2, 2);
+            
+            for (club_id, session_id) in SessionClosings::<T>::take(n) {
+                weight = weight.saturating_add(Self::close_session(club_id, session_id));
+            }
+            
+            for club_id in SessionOpenings::<T>::take(n) {
+                weight = weight.saturating_add(Self::open_session(club_id, n));
+            }
+            
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new proposal
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            5 + 5 * T::MaxMembersPerClub::get() as u64,
+            5 + T::MaxMembersPerClub::get() as u64,
+        ))]
+        #[pallet::call_index(0)]
+        pub fn create_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_type: ProposalType,
+            voting_mechanism: VotingMechanism,
+            title: Vec<u8>,
+            summary: Vec<u8>,
+            content: Option<ContentRef>,
+            voting_duration: BlockNumberFor<T>,
+            approval_threshold: u8,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            
+            // Validate approval threshold
+            ensure!(
+                approval_threshold > 0 && approval_threshold <= 100,
+                Error::<T>::InvalidApprovalThreshold
+            );
+            
+            Self::do_create_proposal(
+                proposer,
+                club_id,
+                proposal_type,
+                voting_mechanism,
+                title,
+                summary,
+                content,
+                voting_duration,
+                approval_threshold,
+                true,
+            )?;
+            
+            Ok(())
+        }
+
+        /// Cast a vote on a proposal
+        ///
+        /// Reads the proposal, its ballot kind, the voter's membership and
+        /// snapshotted power, and reserves the cost of a quadratic vote.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(10, 3))]
+        #[pallet::call_index(1)]
+        pub fn vote(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            choice: VoteChoice,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now <= proposal.voting_end,
+                Error::<T>::VotingPeriodEnded
+            );
+            
+            ensure!(
+                !SecretBallots::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::SecretBallot
+            );
+            ensure!(
+                !ProposalOptions::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::MultiOptionProposal
+            );
+            
+            Self::do_vote(proposal, voter, choice, now)
+        }
+
+        /// Finalize a proposal and check if it passed
+        ///
+        /// Can be called before `voting_end` once the voting power of members
+        /// who have not yet voted is no longer enough to change the outcome.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            6 + 10 * T::MaxMembersPerClub::get() as u64,
+            5 + 6 * T::MaxMembersPerClub::get() as u64,
+        ))]
+        #[pallet::call_index(2)]
+        pub fn finalize_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            
+            if let Some(ballot) = Self::proposal_options(club_id, proposal_id) {
+                ensure!(now > proposal.voting_end, Error::<T>::VotingPeriodNotEnded);
+                Self::conclude_options(proposal, ballot);
+                return Ok(());
+            }
+            
+            // Secret ballots cannot be decided early since tallies stay
+            // unknown until the reveal phase is over
+            if let Some(ballot) = Self::secret_ballot(club_id, proposal_id) {
+                ensure!(
+                    now > proposal.voting_end.saturating_add(ballot.reveal_period),
+                    Error::<T>::RevealPhaseNotEnded
+                );
+                Self::penalize_unrevealed(club_id, proposal_id);
+            }
+            
+            let finalized_early = now <= proposal.voting_end;
+            if finalized_early {
+                ensure!(
+                    Self::decided_outcome(&proposal).is_some(),
+                    Error::<T>::OutcomeNotDecided
+                );
+            }
+            
+            ensure!(
+                !ProposalSessions::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::ProposalInSession
+            );
+            
+            let status = Self::conclude(proposal, !finalized_early);
+            
+            if finalized_early {
+                Self::deposit_event(Event::ProposalFinalizedEarly {
+                    club_id,
+                    proposal_id,
+                    passed: status == ProposalStatus::Passed,
+                });
+            }
+            
+            Ok(())
+        }
+
+        /// Publish a new revision of a proposal
+        ///
+        /// Once votes have been cast, the proposer must explicitly reset them
+        /// so that nobody's vote stands for a body they have not seen.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            7 + 4 * T::MaxMembersPerClub::get() as u64,
+            5 + 5 * T::MaxMembersPerClub::get() as u64,
+        ))]
+        #[pallet::call_index(3)]
+        pub fn amend_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            content_hash: ContentHash,
+            summary: Vec<u8>,
+            reset_votes: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let mut proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+            ensure!(
+                matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Scheduled),
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                proposal.status == ProposalStatus::Scheduled || now <= proposal.voting_end,
+                Error::<T>::VotingPeriodEnded
+            );
+            
+            let summary = BoundedVec::try_from(summary)
+                .map_err(|_| Error::<T>::InvalidSummary)?;
+            
+            let has_votes = Votes::<T>::iter_prefix((club_id, proposal_id)).next().is_some()
+                || OptionVotes::<T>::iter_prefix((club_id, proposal_id)).next().is_some()
+                || VoteCommitments::<T>::iter_prefix((club_id, proposal_id)).next().is_some();
+            ensure!(!has_votes || reset_votes, Error::<T>::VotesAlreadyCast);
+            
+            let votes_reset = has_votes;
+            if votes_reset {
+                Self::reset_votes(&mut proposal);
+                Proposals::<T>::insert(club_id, proposal_id, &proposal);
+            }
+            
+            let version = Self::revision_count(club_id, proposal_id).saturating_add(1);
+            RevisionCount::<T>::insert(club_id, proposal_id, version);
+            ProposalRevisions::<T>::insert(
+                (club_id, proposal_id, version),
+                ProposalRevision {
+                    version,
+                    content_hash,
+                    summary,
+                    author: who,
+                    created_at: now,
+                    votes_reset,
+                },
+            );
+            
+            Self::deposit_event(Event::ProposalAmended {
+                club_id,
+                proposal_id,
+                version,
+                votes_reset,
+            });
+            
+            Ok(())
+        }
+
+        /// Post a comment on a proposal
+        ///
+        /// Checks the author's membership and appends to the proposal's
+        /// comments, of which there are at most `MaxCommentsPerProposal`.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5, 1))]
+        #[pallet::call_index(4)]
+        pub fn post_comment(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            content_hash: ContentHash,
+        ) -> DispatchResult {
+            let author = ensure_signed(origin)?;
+            
+            ensure!(
+                Proposals::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::ProposalNotFound
+            );
+            ensure!(
+                T::Members::is_member(club_id, &author),
+                Error::<T>::NotClubMember
+            );
+            
+            let comment = ProposalComment {
+                author: author.clone(),
+                content_hash,
+                revision: Self::revision_count(club_id, proposal_id),
+                posted_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            
+            ProposalComments::<T>::try_mutate(club_id, proposal_id, |comments| {
+                comments.try_push(comment)
+                    .map_err(|_| Error::<T>::MaxCommentsExceeded)
+            })?;
+            
+            Self::deposit_event(Event::CommentPosted {
+                club_id,
+                proposal_id,
+                author,
+                content_hash,
+            });
+            
+            Ok(())
+        }
+
+        /// Keep a small document body on-chain against a deposit
+        ///
+        /// The body can then be referenced from proposals, disputes and
+        /// evidence by its hash.
+        #[pallet::weight(Pallet::<T>::note_weight(data.len()))]
+        #[pallet::call_index(5)]
+        pub fn note_content(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+        ) -> DispatchResult {
+            let depositor = ensure_signed(origin)?;
+            
+            let size = data.len() as u32;
+            let data: BoundedVec<u8, T::MaxNoteSize> = BoundedVec::try_from(data)
+                .map_err(|_| Error::<T>::NoteTooLarge)?;
+            
+            let hash = T::Hashing::hash(&data);
+            ensure!(!Notes::<T>::contains_key(hash), Error::<T>::AlreadyNoted);
+            
+            let deposit = T::NoteDepositBase::get()
+                .saturating_add(T::NoteDepositPerByte::get().saturating_mul(size.into()));
+            T::Currency::reserve(&depositor, deposit)?;
+            
+            Notes::<T>::insert(hash, Note {
+                depositor: depositor.clone(),
+                deposit,
+                data,
+            });
+            
+            Self::deposit_event(Event::ContentNoted {
+                hash,
+                depositor,
+                size,
+            });
+            
+            Ok(())
+        }
+
+        /// Remove a note and release its deposit
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 2))]
+        #[pallet::call_index(6)]
+        pub fn unnote_content(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let note = Notes::<T>::get(hash).ok_or(Error::<T>::NoteNotFound)?;
+            ensure!(note.depositor == who, Error::<T>::NotNoteDepositor);
+            
+            T::Currency::unreserve(&note.depositor, note.deposit);
+            Notes::<T>::remove(hash);
+            
+            Self::deposit_event(Event::ContentUnnoted { hash });
+            
+            Ok(())
+        }
+
+        /// Hold governance sessions for a club at a recurring block interval
+        ///
+        /// Proposals created while a schedule is set wait for the next session
+        /// and are voted on and finalized together with the other proposals of
+        /// that session.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        #[pallet::call_index(7)]
+        pub fn set_session_schedule(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            interval: BlockNumberFor<T>,
+            voting_period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_admin(club_id, &who), Error::<T>::NotClubAdmin);
+            ensure!(
+                !voting_period.is_zero()
+                    && voting_period <= interval
+                    && voting_period <= T::MaxVotingDuration::get(),
+                Error::<T>::InvalidSessionSchedule
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            let next_session_at = now.saturating_add(interval);
+            SessionOpenings::<T>::try_mutate(next_session_at, |clubs| {
+                clubs.try_push(club_id)
+                    .map_err(|_| Error::<T>::TooManySessionsInBlock)
+            })?;
+            
+            SessionSchedules::<T>::insert(club_id, SessionSchedule {
+                interval,
+                voting_period,
+                next_session_at,
+            });
+            
+            Self::deposit_event(Event::SessionScheduleSet {
+                club_id,
+                interval,
+                voting_period,
+                next_session_at,
+            });
+            
+            Ok(())
+        }
+
+        /// Stop holding governance sessions for a club
+        ///
+        /// Proposals still waiting on the agenda open for voting immediately
+        /// with the voting period of the removed schedule.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        #[pallet::call_index(8)]
+        pub fn clear_session_schedule(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_admin(club_id, &who), Error::<T>::NotClubAdmin);
+            let schedule = SessionSchedules::<T>::take(club_id)
+                .ok_or(Error::<T>::NoSessionSchedule)?;
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::release_agenda(club_id, now.saturating_add(schedule.voting_period));
+            
+            Self::deposit_event(Event::SessionScheduleCleared { club_id });
+            
+            Ok(())
+        }
+
+        /// Set the bond a club requires for secret ballot commitments
+        ///
+        /// The bond is forfeited by voters who commit but never reveal.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(9)]
+        pub fn set_secret_ballot_bond(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            bond: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_admin(club_id, &who), Error::<T>::NotClubAdmin);
+            SecretBallotBonds::<T>::insert(club_id, bond);
+            
+            Self::deposit_event(Event::SecretBallotBondSet { club_id, bond });
+            
+            Ok(())
+        }
+
+        /// Switch a proposal to commit-reveal voting
+        ///
+        /// Votes are committed as hashes until `voting_end` and revealed
+        /// during the following `reveal_period` blocks.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(10)]
+        pub fn enable_secret_ballot(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            reveal_period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            ensure!(
+                !ProposalSessions::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::ProposalInSession
+            );
+            ensure!(
+                !ProposalOptions::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::MultiOptionProposal
+            );
+            ensure!(
+                !reveal_period.is_zero() && reveal_period <= T::MaxVotingDuration::get(),
+                Error::<T>::InvalidVotingDuration
+            );
+            ensure!(
+                Votes::<T>::iter_prefix((club_id, proposal_id)).next().is_none(),
+                Error::<T>::VotesAlreadyCast
+            );
+            
+            SecretBallots::<T>::insert(club_id, proposal_id, SecretBallot {
+                reveal_period,
+                bond: Self::secret_ballot_bond(club_id),
+            });
+            
+            Self::deposit_event(Event::SecretBallotEnabled {
+                club_id,
+                proposal_id,
+                reveal_period,
+            });
+            
+            Ok(())
+        }
+
+        /// Commit a sealed vote on a secret ballot
+        ///
+        /// `commitment` is the hash of the SCALE-encoded `(voter, proposal_id,
+        /// choice, salt)` tuple, so that it cannot be copied by other voters or
+        /// reused on other proposals.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(11)]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let ballot = Self::secret_ballot(club_id, proposal_id)
+                .ok_or(Error::<T>::NotSecretBallot)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now <= proposal.voting_end,
+                Error::<T>::VotingPeriodEnded
+            );
+            ensure!(
+                Self::is_eligible(&proposal, &voter),
+                Error::<T>::NotEligibleVoter
+            );
+            ensure!(
+                !VoteCommitments::<T>::contains_key((club_id, proposal_id, &voter)),
+                Error::<T>::AlreadyCommitted
+            );
+            
+            T::Currency::reserve(&voter, ballot.bond)?;
+            VoteCommitments::<T>::insert((club_id, proposal_id, &voter), VoteCommitment {
+                commitment,
+                bond: ballot.bond,
+            });
+            
+            Self::deposit_event(Event::VoteCommitted {
+                club_id,
+                proposal_id,
+                voter,
+            });
+            
+            Ok(())
+        }
+
+        /// Reveal a committed vote, adding it to the tally
+        ///
+        /// Checks the commitment, refunds its bond and casts the vote like `vote`.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(11, 5))]
+        #[pallet::call_index(12)]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            choice: VoteChoice,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let ballot = Self::secret_ballot(club_id, proposal_id)
+                .ok_or(Error::<T>::NotSecretBallot)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now > proposal.voting_end
+                    && now <= proposal.voting_end.saturating_add(ballot.reveal_period),
+                Error::<T>::NotRevealPhase
+            );
+            
+            let commitment = VoteCommitments::<T>::get((club_id, proposal_id, &voter))
+                .ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(
+                T::Hashing::hash_of(&(&voter, proposal_id, &choice, salt)) == commitment.commitment,
+                Error::<T>::InvalidReveal
+            );
+            
+            VoteCommitments::<T>::remove((club_id, proposal_id, &voter));
+            T::Currency::unreserve(&voter, commitment.bond);
+            
+            Self::do_vote(proposal, voter, choice, now)
+        }
+
+        /// Create a proposal choosing among several options
+        ///
+        /// Each option may carry an encoded call that is dispatched with the
+        /// club origin once the proposal passes with that option. The winning
+        /// option needs `approval_threshold` percent of the power of everyone
+        /// who voted. Multi-option proposals are not bundled into governance
+        /// sessions.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            5 + 5 * T::MaxMembersPerClub::get() as u64,
+            7 + T::MaxMembersPerClub::get() as u64,
+        ))]
+        #[pallet::call_index(13)]
+        pub fn create_multi_option_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_type: ProposalType,
+            title: Vec<u8>,
+            summary: Vec<u8>,
+            content: Option<ContentRef>,
+            voting_duration: BlockNumberFor<T>,
+            approval_threshold: u8,
+            method: TallyMethod,
+            options: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            
+            ensure!(
+                approval_threshold > 0 && approval_threshold <= 100,
+                Error::<T>::InvalidApprovalThreshold
+            );
+            ensure!(
+                options.len() >= 2 && options.len() <= T::MaxProposalOptions::get() as usize,
+                Error::<T>::InvalidOptionCount
+            );
+            
+            let mut bounded_options = BoundedVec::<ProposalOption, T::MaxProposalOptions>::default();
+            for (label, call) in options {
+                ensure!(!label.is_empty(), Error::<T>::InvalidOptionLabel);
+                let call = match call {
+                    Some(call) => {
+                        ensure!(
+                            <T as Config>::RuntimeCall::decode(&mut &call[..]).is_ok(),
+                            Error::<T>::InvalidOptionCall
+                        );
+                        Some(EncodedCall::try_from(call).map_err(|_| Error::<T>::InvalidOptionCall)?)
+                    },
+                    None => None,
+                };
+                bounded_options
+                    .try_push(ProposalOption {
+                        label: BoundedVec::try_from(label)
+                            .map_err(|_| Error::<T>::InvalidOptionLabel)?,
+                        call,
+                    })
+                    .map_err(|_| Error::<T>::InvalidOptionCount)?;
+            }
+            
+            let option_count = bounded_options.len();
+            let proposal_id = Self::do_create_proposal(
+                proposer,
+                club_id,
+                proposal_type,
+                VotingMechanism::SimpleMajority,
+                title,
+                summary,
+                content,
+                voting_duration,
+                approval_threshold,
+                false,
+            )?;
+            
+            ProposalOptions::<T>::insert(club_id, proposal_id, OptionBallot {
+                method,
+                options: bounded_options,
+            });
+            OptionResults::<T>::insert(
+                club_id,
+                proposal_id,
+                BoundedVec::truncate_from(sp_std::vec![Zero::zero(); option_count]),
+            );
+            
+            Ok(())
+        }
+
+        /// Vote on a multi-option proposal
+        ///
+        /// `selection` holds one option for plurality voting, the approved
+        /// options for approval voting, or a ranking from most to least
+        /// preferred for instant-runoff voting.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(9, 2))]
+        #[pallet::call_index(14)]
+        pub fn vote_options(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            selection: Vec<OptionIndex>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let ballot = Self::proposal_options(club_id, proposal_id)
+                .ok_or(Error::<T>::NotMultiOptionProposal)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Active,
+                Error::<T>::ProposalNotActive
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now <= proposal.voting_end,
+                Error::<T>::VotingPeriodEnded
+            );
+            ensure!(
+                Self::is_eligible(&proposal, &voter),
+                Error::<T>::NotEligibleVoter
+            );
+            ensure!(
+                !OptionVotes::<T>::contains_key((club_id, proposal_id, &voter)),
+                Error::<T>::AlreadyVoted
+            );
+            
+            // Every option at most once, and exactly one for plurality voting
+            let option_count = ballot.options.len() as OptionIndex;
+            ensure!(
+                !selection.is_empty()
+                    && selection.iter().all(|index| *index < option_count)
+                    && selection.iter().enumerate().all(|(i, index)| !selection[..i].contains(index))
+                    && (ballot.method != TallyMethod::Plurality || selection.len() == 1),
+                Error::<T>::InvalidSelection
+            );
+            
+            let power = Self::voter_power((club_id, proposal_id, &voter));
+            
+            // Instant-runoff ballots only count towards their first preference
+            // until the proposal is finalized
+            let counted = match ballot.method {
+                TallyMethod::InstantRunoff => &selection[..1],
+                _ => &selection[..],
+            };
+            OptionResults::<T>::mutate(club_id, proposal_id, |results| {
+                for index in counted {
+                    if let Some(total) = results.get_mut(*index as usize) {
+                        *total = total.saturating_add(power);
+                    }
+                }
+            });
+            
+            OptionVotes::<T>::insert((club_id, proposal_id, &voter), OptionVote {
+                selection: BoundedVec::try_from(selection.clone())
+                    .map_err(|_| Error::<T>::InvalidSelection)?,
+                power,
+                cast_at: now,
+            });
+            
+            Self::deposit_event(Event::OptionVoteCast {
+                club_id,
+                proposal_id,
+                voter,
+                selection,
+                power,
+            });
+            
+            Ok(())
+        }
+
+        /// Dispatch the call of the winning option of a passed proposal
+        /// with the club origin once its veto period is over
+        ///
+        /// `weight_bound` must cover the weight of the call. A call that fails
+        /// is recorded in the event and can be enacted again.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4, 2) + *weight_bound)]
+        #[pallet::call_index(15)]
+        pub fn enact_option(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            weight_bound: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let mut winner = Self::winning_option(club_id, proposal_id)
+                .ok_or(Error::<T>::NoWinningOption)?;
+            ensure!(!winner.enacted, Error::<T>::OptionAlreadyEnacted);
+            
+            // The winning option's call is held as the proposal's action
+            let action = Self::proposal_action(club_id, proposal_id)
+                .ok_or(Error::<T>::OptionHasNoCall)?;
+            
+            let (result, call_weight) = Self::enact_action(&proposal, action, weight_bound)?;
+            if result.is_ok() {
+                winner.enacted = true;
+                WinningOptions::<T>::insert(club_id, proposal_id, &winner);
+            }
+            
+            Self::deposit_event(Event::OptionEnacted {
+                club_id,
+                proposal_id,
+                option: winner.index,
+                result,
+            });
+            
+            Ok(Some(Self::enactment_weight(call_weight)).into())
+        }
+
+        /// Set the guardian council of the club whose governance dispatches this call
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(16)]
+        pub fn set_guardian_council(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            min_amount: BalanceOf<T>,
+            veto_period: BlockNumberFor<T>,
+            override_period: BlockNumberFor<T>,
+            override_threshold: u8,
+        ) -> DispatchResult {
+            let club_id = T::ClubOrigin::ensure_origin(origin)?;
+            
+            ensure!(
+                !guardians.is_empty()
+                    && !veto_period.is_zero()
+                    && !override_period.is_zero()
+                    && override_period <= T::MaxVotingDuration::get()
+                    && override_threshold > 50 && override_threshold <= 100,
+                Error::<T>::InvalidGuardianCouncil
+            );
+            
+            let council = GuardianCouncil {
+                guardians: BoundedVec::try_from(guardians.clone())
+                    .map_err(|_| Error::<T>::InvalidGuardianCouncil)?,
+                min_amount,
+                veto_period,
+                override_period,
+                override_threshold,
+            };
+            GuardianCouncils::<T>::insert(club_id, council);
+            
+            Self::deposit_event(Event::GuardianCouncilSet { club_id, guardians });
+            
+            Ok(())
+        }
+
+        /// Remove the guardian council of the club whose governance dispatches this call
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(17)]
+        pub fn remove_guardian_council(origin: OriginFor<T>) -> DispatchResult {
+            let club_id = T::ClubOrigin::ensure_origin(origin)?;
+            
+            GuardianCouncils::<T>::remove(club_id);
+            
+            Self::deposit_event(Event::GuardianCouncilRemoved { club_id });
+            
+            Ok(())
+        }
+
+        /// Attach the action a proposal enacts once it passes
+        ///
+        /// The club funds the call moves decide whether an investment proposal
+        /// is subject to guardian veto. The action cannot change once votes
+        /// have been cast or committed.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4, 1))]
+        #[pallet::call_index(18)]
+        pub fn set_proposal_action(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            call: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+            ensure!(
+                matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Scheduled),
+                Error::<T>::ProposalNotActive
+            );
+            ensure!(
+                !ProposalOptions::<T>::contains_key(club_id, proposal_id),
+                Error::<T>::MultiOptionProposal
+            );
+            ensure!(
+                Votes::<T>::iter_prefix((club_id, proposal_id)).next().is_none()
+                    && VoteCommitments::<T>::iter_prefix((club_id, proposal_id)).next().is_none(),
+                Error::<T>::VotesAlreadyCast
+            );
+            let amount = <T as Config>::RuntimeCall::decode(&mut &call[..])
+                .map(|call| T::Spends::spend_of(&call))
+                .map_err(|_| Error::<T>::InvalidOptionCall)?;
+            
+            ProposalActions::<T>::insert(club_id, proposal_id, ProposalAction {
+                amount,
+                call: EncodedCall::try_from(call).map_err(|_| Error::<T>::InvalidOptionCall)?,
+                enactable_at: None,
+                enacted: false,
+            });
+            
+            Self::deposit_event(Event::ProposalActionSet {
+                club_id,
+                proposal_id,
+                amount,
+            });
+            
+            Ok(())
+        }
+
+        /// Veto a passed proposal during its veto period
+        ///
+        /// The reasoning is published off-chain and referenced by its hash.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(3))]
+        #[pallet::call_index(19)]
+        pub fn veto_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            reason: ContentHash,
+        ) -> DispatchResult {
+            let guardian = ensure_signed(origin)?;
+            
+            let council = Self::guardian_council(club_id)
+                .ok_or(Error::<T>::NotGuardian)?;
+            ensure!(council.guardians.contains(&guardian), Error::<T>::NotGuardian);
+            
+            let mut proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let mut action = Self::proposal_action(club_id, proposal_id)
+                .ok_or(Error::<T>::NoProposalAction)?;
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                proposal.status == ProposalStatus::Passed
+                    && !action.enacted
+                    && !Vetoes::<T>::contains_key(club_id, proposal_id)
+                    && action.enactable_at.map_or(false, |at| now < at),
+                Error::<T>::NotVetoable
+            );
+            
+            proposal.status = ProposalStatus::Vetoed;
+            Proposals::<T>::insert(club_id, proposal_id, &proposal);
+            action.enactable_at = None;
+            ProposalActions::<T>::insert(club_id, proposal_id, &action);
+            Vetoes::<T>::insert(club_id, proposal_id, Veto {
+                guardian: guardian.clone(),
+                reason,
+                vetoed_at: now,
+                overridden: false,
+            });
+            
+            Self::deposit_event(Event::ProposalVetoed {
+                club_id,
+                proposal_id,
+                guardian,
+                reason,
+            });
+            
+            Ok(())
+        }
+
+        /// Start a re-vote on a vetoed proposal
+        ///
+        /// Earlier votes are discarded. The proposal passes again, without a
+        /// further veto period, only with the council's override threshold.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            8 + 7 * T::MaxMembersPerClub::get() as u64,
+            6 + 6 * T::MaxMembersPerClub::get() as u64,
+        ))]
+        #[pallet::call_index(20)]
+        pub fn override_veto(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let mut proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            
+            ensure!(
+                proposal.status == ProposalStatus::Vetoed,
+                Error::<T>::ProposalNotVetoed
+            );
+            
+            // The electorate was released when the proposal passed; the
+            // re-vote is open to the same members at their current balances
+            Self::snapshot_electorate(&proposal)?;
+            ensure!(
+                Self::is_eligible(&proposal, &who),
+                Error::<T>::NotEligibleVoter
+            );
+            
+            let override_period = Self::guardian_council(club_id)
+                .map_or_else(T::MaxVotingDuration::get, |council| council.override_period);
+            let now = <frame_system::Pallet<T>>::block_number();
+            
+            ActiveProposals::<T>::try_mutate(club_id, |active| {
+                active.try_push(proposal_id)
+                    .map_err(|_| Error::<T>::MaxProposalsExceeded)
+            })?;
+            
+            // The re-vote is finalized on its own, outside any session, and
+            // selects its winning option afresh
+            ProposalSessions::<T>::remove(club_id, proposal_id);
+            WinningOptions::<T>::remove(club_id, proposal_id);
+            
+            Self::reset_votes(&mut proposal);
+            proposal.status = ProposalStatus::Active;
+            proposal.voting_end = now.saturating_add(override_period);
+            Proposals::<T>::insert(club_id, proposal_id, &proposal);
+            
+            Self::deposit_event(Event::VetoOverrideStarted {
+                club_id,
+                proposal_id,
+                voting_end: proposal.voting_end,
+            });
+            
+            Ok(())
+        }
+
+        /// Dispatch the action of a passed proposal with the club origin once
+        /// its veto period is over
+        ///
+        /// `weight_bound` must cover the weight of the call. A call that fails
+        /// is recorded in the event and can be enacted again.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3, 1) + *weight_bound)]
+        #[pallet::call_index(21)]
+        pub fn enact_proposal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            weight_bound: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            
+            let proposal = Proposals::<T>::get(club_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            let action = Self::proposal_action(club_id, proposal_id)
+                .ok_or(Error::<T>::NoProposalAction)?;
+            
+            ensure!(!action.enacted, Error::<T>::AlreadyEnacted);
+            
+            let (result, call_weight) = Self::enact_action(&proposal, action, weight_bound)?;
+            
+            Self::deposit_event(Event::ProposalEnacted {
+                club_id,
+                proposal_id,
+                result,
+            });
+            
+            Ok(Some(Self::enactment_weight(call_weight)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Create a proposal, queueing it for the next governance session if
+        /// `schedulable` and the club holds sessions
+        #[allow(clippy::too_many_arguments)]
+        fn do_create_proposal(
+            proposer: T::AccountId,
+            club_id: ClubId,
+            proposal_type: ProposalType,
+            voting_mechanism: VotingMechanism,
+            title: Vec<u8>,
+            summary: Vec<u8>,
+            content: Option<ContentRef>,
+            voting_duration: BlockNumberFor<T>,
+            approval_threshold: u8,
+            schedulable: bool,
+        ) -> Result<ProposalId, DispatchError> {
+            // Validate voting duration
+            ensure!(
+                voting_duration <= T::MaxVotingDuration::get(),
+                Error::<T>::InvalidVotingDuration
+            );
+            
+            // Check deposit requirement
+            let deposit = T::MinProposalDeposit::get();
+            T::Currency::reserve(&proposer, deposit)?;
+            
+            // Get next proposal ID
+            let proposal_id = Self::proposal_count(club_id);
+            let new_count = proposal_id.saturating_add(1);
+            ProposalCount::<T>::insert(club_id, new_count);
+            
+            // Check max proposals limit
+            let mut active = Self::active_proposals(club_id);
+            ensure!(
+                active.len() < T::MaxProposalsPerClub::get() as usize,
+                Error::<T>::MaxProposalsExceeded
+            );
             
             let now = <frame_system::Pallet<T>>::block_number();
             let voting_end = now.saturating_add(voting_duration);
             
+            // Clubs with a session schedule vote on proposals together; the
+            // voting window is set when the next session opens
+            let scheduled = schedulable && SessionSchedules::<T>::contains_key(club_id);
+            if scheduled {
+                SessionAgenda::<T>::try_mutate(club_id, |agenda| {
+                    agenda.try_push(proposal_id)
+                        .map_err(|_| Error::<T>::SessionAgendaFull)
+                })?;
+            }
+            
             let proposal = Proposal {
                 id: proposal_id,
                 club_id,
@@ -322,13 +2290,14 @@ pub mod pallet {
                 proposal_type: proposal_type.clone(),
                 voting_mechanism,
                 title: BoundedVec::try_from(title)
-                    .map_err(|_| Error::<T>::MaxProposalsExceeded)?,
-                description: BoundedVec::try_from(description)
-                    .map_err(|_| Error::<T>::MaxProposalsExceeded)?,
+                    .map_err(|_| Error::<T>::InvalidTitle)?,
+                summary: BoundedVec::try_from(summary)
+                    .map_err(|_| Error::<T>::InvalidSummary)?,
+                content,
                 deposit,
                 created_at: now,
                 voting_end,
-                status: ProposalStatus::Active,
+                status: if scheduled { ProposalStatus::Scheduled } else { ProposalStatus::Active },
                 aye_votes: Zero::zero(),
                 nay_votes: Zero::zero(),
                 abstain_votes: Zero::zero(),
@@ -340,6 +2309,10 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::MaxProposalsExceeded)?;
             ActiveProposals::<T>::insert(club_id, active);
             
+            // Members joining from now on are not part of the electorate, and
+            // balances moved from now on do not change anyone's voting power
+            Self::snapshot_electorate(&proposal)?;
+            
             Self::deposit_event(Event::ProposalCreated {
                 club_id,
                 proposal_id,
@@ -347,32 +2320,21 @@ pub mod pallet {
                 proposal_type,
             });
             
-            Ok(())
+            Ok(proposal_id)
         }
-
-        /// Cast a vote on a proposal
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(1)]
-        pub fn vote(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            proposal_id: ProposalId,
+        
+        /// Record a vote and add its weighted power to the proposal tally
+        fn do_vote(
+            mut proposal: Proposal<T>,
+            voter: T::AccountId,
             choice: VoteChoice,
+            now: BlockNumberFor<T>,
         ) -> DispatchResult {
-            let voter = ensure_signed(origin)?;
-            
-            let mut proposal = Proposals::<T>::get(club_id, proposal_id)
-                .ok_or(Error::<T>::ProposalNotFound)?;
-            
-            ensure!(
-                proposal.status == ProposalStatus::Active,
-                Error::<T>::ProposalNotActive
-            );
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
             
-            let now = <frame_system::Pallet<T>>::block_number();
             ensure!(
-                now <= proposal.voting_end,
-                Error::<T>::VotingPeriodEnded
+                Self::is_eligible(&proposal, &voter),
+                Error::<T>::NotEligibleVoter
             );
             
             // Check if already voted
@@ -381,52 +2343,15 @@ pub mod pallet {
                 Error::<T>::AlreadyVoted
             );
             
-            // Get voting power (balance)
-            let voting_power = T::Currency::free_balance(&voter);
+            // Voting power as of the proposal's creation
+            let weighted_power = Self::voter_power((club_id, proposal_id, &voter));
             
-            // Apply voting mechanism
-            let (weighted_power, cost) = match proposal.voting_mechanism {
-                VotingMechanism::SimpleMajority => {
-                    // Simple majority: 1 vote = 1 unit of balance
-                    (voting_power, voting_power)
-                },
-                VotingMechanism::Quadratic => {
-                    // Quadratic voting: cost = votes^2
-                    // For MVP: Calculate max votes user can afford: votes = sqrt(balance)
-                    // Then cost = votes^2 = balance (approximately)
-                    // This ensures cost = votes^2 relationship
-                    let max_votes = Self::integer_sqrt(voting_power);
-                    
-                    // For MVP, user votes with max_votes, cost = max_votes^2
-                    let cost = Self::multiply_checked(max_votes, max_votes)
-                        .unwrap_or(voting_power);
-                    
-                    // Cap cost at available balance
-                    let actual_cost = if cost > voting_power {
-                        voting_power
-                    } else {
-                        cost
-                    };
-                    
-                    // Recalculate votes based on actual cost: votes = sqrt(cost)
-                    let actual_votes = Self::integer_sqrt(actual_cost);
-                    
-                    // Reserve the cost
-                    T::Currency::reserve(&voter, actual_cost)?;
-                    
-                    (actual_votes, actual_cost)
-                },
-                VotingMechanism::Conviction => {
-                    // Conviction voting: longer lock = more weight
-                    // Simplified for MVP: use balance as power
-                    (voting_power, voting_power)
-                },
-                VotingMechanism::Delegated => {
-                    // Delegated voting: use delegated power
-                    // Simplified for MVP: use balance as power
-                    (voting_power, voting_power)
-                },
-            };
+            // Quadratic votes reserve the square of the power cast
+            if proposal.voting_mechanism == VotingMechanism::Quadratic {
+                let cost = Self::multiply_checked(weighted_power, weighted_power)
+                    .unwrap_or(weighted_power);
+                T::Currency::reserve(&voter, cost)?;
+            }
             
             let vote = Vote {
                 voter: voter.clone(),
@@ -456,75 +2381,717 @@ pub mod pallet {
             
             Ok(())
         }
-
-        /// Finalize a proposal and check if it passed
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(2)]
-        pub fn finalize_proposal(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            proposal_id: ProposalId,
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+        
+        /// Move the bonds of all commitments left unrevealed on a secret
+        /// ballot into the club treasury
+        ///
+        /// Only snapshotted members commit, so there are at most
+        /// `MaxMembersPerClub` commitments.
+        fn penalize_unrevealed(club_id: ClubId, proposal_id: ProposalId) {
+            let commitments = VoteCommitments::<T>::drain_prefix((club_id, proposal_id))
+                .take(T::MaxMembersPerClub::get() as usize);
+            for (voter, commitment) in commitments {
+                T::Currency::unreserve(&voter, commitment.bond);
+                let amount = match T::Treasury::deposit_from(club_id, &voter, commitment.bond) {
+                    Ok(()) => commitment.bond,
+                    // Funds that cannot be moved, e.g. because they are locked, are burned
+                    Err(_) => T::Currency::withdraw(
+                        &voter,
+                        commitment.bond,
+                        WithdrawReasons::all(),
+                        ExistenceRequirement::AllowDeath,
+                    )
+                    .map_or_else(|_| Zero::zero(), |_| commitment.bond),
+                };
+                Self::deposit_event(Event::UnrevealedVotePenalized {
+                    club_id,
+                    proposal_id,
+                    voter,
+                    amount,
+                });
+            }
+        }
+        
+        /// Close voting on a proposal, recording whether it passed, was
+        /// rejected or expired without votes
+        fn conclude(mut proposal: Proposal<T>, may_expire: bool) -> ProposalStatus {
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
             
-            let mut proposal = Proposals::<T>::get(club_id, proposal_id)
-                .ok_or(Error::<T>::ProposalNotFound)?;
+            let total_votes = Self::turnout(&proposal);
             
-            ensure!(
-                proposal.status == ProposalStatus::Active,
-                Error::<T>::ProposalNotActive
-            );
+            // Abstentions count towards the quorum but not the approval threshold
+            if total_votes.is_zero() && may_expire {
+                proposal.status = ProposalStatus::Expired;
+                Self::deposit_event(Event::ProposalExpired { club_id, proposal_id });
+            } else if Self::passes(&proposal, proposal.aye_votes, proposal.nay_votes, total_votes) {
+                proposal.status = ProposalStatus::Passed;
+                Self::deposit_event(Event::ProposalPassed { club_id, proposal_id });
+                Self::schedule_enactment(&proposal);
+            } else {
+                proposal.status = ProposalStatus::Rejected;
+                Self::deposit_event(Event::ProposalRejected { club_id, proposal_id });
+            }
+            Proposals::<T>::insert(club_id, proposal_id, &proposal);
             
-            let now = <frame_system::Pallet<T>>::block_number();
-            ensure!(
-                now > proposal.voting_end,
-                Error::<T>::VotingPeriodEnded
-            );
+            // Remove from active proposals
+            ActiveProposals::<T>::mutate(club_id, |active| active.retain(|&id| id != proposal_id));
+            Self::release_electorate(club_id, proposal_id);
             
-            let total_votes = proposal.aye_votes
-                .saturating_add(proposal.nay_votes)
-                .saturating_add(proposal.abstain_votes);
+            proposal.status
+        }
+        
+        /// Close voting on a multi-option proposal, selecting the option with
+        /// the most power under its tally method
+        fn conclude_options(mut proposal: Proposal<T>, ballot: OptionBallot<T>) {
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
             
-            if total_votes.is_zero() {
-                proposal.status = ProposalStatus::Expired;
-                Proposals::<T>::insert(club_id, proposal_id, &proposal);
-                Self::deposit_event(Event::ProposalExpired { club_id, proposal_id });
-                return Ok(());
+            if ballot.method == TallyMethod::InstantRunoff {
+                let results = Self::instant_runoff(club_id, proposal_id, ballot.options.len());
+                OptionResults::<T>::insert(club_id, proposal_id, BoundedVec::truncate_from(results));
             }
             
-            // Calculate approval percentage
-            // Simplified calculation - full implementation would use proper fixed point math
-            // For now, we compare aye_votes to total_votes (excluding abstains)
-            let voting_votes = proposal.aye_votes.saturating_add(proposal.nay_votes);
-            let passed = if voting_votes.is_zero() {
-                false
-            } else {
-                // Check if aye votes are greater than nay votes and meet threshold
-                // This is a simplified check - full implementation would calculate percentage properly
-                proposal.aye_votes > proposal.nay_votes
+            let results = Self::option_results(club_id, proposal_id);
+            let best = results.iter().max().copied().unwrap_or_else(Zero::zero);
+            let mut leaders = results.iter().enumerate().filter(|(_, power)| **power == best);
+            
+            // Power of everyone who voted, counted once however many options
+            // they approved
+            let turnout = OptionVotes::<T>::iter_prefix_values((club_id, proposal_id))
+                .take(T::MaxMembersPerClub::get() as usize)
+                .fold(Zero::zero(), |total: BalanceOf<T>, vote| total.saturating_add(vote.power));
+            let approved = Self::meets_threshold(best, turnout, Self::approval_threshold(&proposal))
+                && Self::meets_quorum(&proposal, turnout);
+            let winner = match (leaders.next(), leaders.next()) {
+                (Some((index, _)), None) if approved => Some(index as OptionIndex),
+                _ => None,
             };
             
-            // Check if proposal passed
-            if passed {
+            if best.is_zero() {
+                proposal.status = ProposalStatus::Expired;
+                Self::deposit_event(Event::ProposalExpired { club_id, proposal_id });
+            } else if let Some(index) = winner {
                 proposal.status = ProposalStatus::Passed;
-                Proposals::<T>::insert(club_id, proposal_id, &proposal);
+                WinningOptions::<T>::insert(club_id, proposal_id, WinningOption {
+                    index,
+                    enacted: false,
+                });
+                
+                // The winning call is enacted like a proposal action, after
+                // any veto period
+                if let Some(call) = ballot.options.get(index as usize).and_then(|option| option.call.clone()) {
+                    let amount = <T as Config>::RuntimeCall::decode(&mut &call[..])
+                        .map_or_else(|_| Zero::zero(), |call| T::Spends::spend_of(&call));
+                    ProposalActions::<T>::insert(club_id, proposal_id, ProposalAction {
+                        amount,
+                        call,
+                        enactable_at: None,
+                        enacted: false,
+                    });
+                    Self::schedule_enactment(&proposal);
+                }
                 Self::deposit_event(Event::ProposalPassed { club_id, proposal_id });
+                Self::deposit_event(Event::OptionSelected {
+                    club_id,
+                    proposal_id,
+                    option: index,
+                });
             } else {
+                // A tie for first place, or a leader short of the approval
+                // threshold or quorum, selects no option
                 proposal.status = ProposalStatus::Rejected;
-                Proposals::<T>::insert(club_id, proposal_id, &proposal);
                 Self::deposit_event(Event::ProposalRejected { club_id, proposal_id });
             }
+            Proposals::<T>::insert(club_id, proposal_id, &proposal);
             
-            // Remove from active proposals
-            let mut active = Self::active_proposals(club_id);
-            active.retain(|&id| id != proposal_id);
-            ActiveProposals::<T>::insert(club_id, active);
+            ActiveProposals::<T>::mutate(club_id, |active| active.retain(|&id| id != proposal_id));
+            Self::release_electorate(club_id, proposal_id);
+        }
+        
+        /// Run instant-runoff rounds over the ranked ballots of a proposal and
+        /// return the power per option in the final round
+        ///
+        /// The option with the least power is eliminated each round, the
+        /// highest index losing ties, until one option holds a majority of
+        /// the ballots still counting.
+        fn instant_runoff(
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            option_count: usize,
+        ) -> Vec<BalanceOf<T>> {
+            let ballots: Vec<OptionVote<T>> = OptionVotes::<T>::iter_prefix_values((club_id, proposal_id)).collect();
+            let mut eliminated = sp_std::vec![false; option_count];
+            
+            loop {
+                let mut results = sp_std::vec![Zero::zero(); option_count];
+                let mut counting: BalanceOf<T> = Zero::zero();
+                for ballot in &ballots {
+                    let preference = ballot.selection.iter()
+                        .map(|index| *index as usize)
+                        .find(|index| *index < option_count && !eliminated[*index]);
+                    if let Some(index) = preference {
+                        results[index] = results[index].saturating_add(ballot.power);
+                        counting = counting.saturating_add(ballot.power);
+                    }
+                }
+                
+                let remaining: Vec<usize> = (0..option_count).filter(|index| !eliminated[*index]).collect();
+                let majority = results.iter()
+                    .any(|power| power.saturating_add(*power) > counting);
+                if remaining.len() <= 1 || majority || counting.is_zero() {
+                    return results;
+                }
+                
+                let weakest = remaining.iter()
+                    .rev()
+                    .min_by_key(|index| results[**index])
+                    .copied();
+                if let Some(index) = weakest {
+                    eliminated[index] = true;
+                }
+            }
+        }
+        
+        /// Open a club's scheduled session with the proposals on its agenda
+        /// and schedule the one after it
+        ///
+        /// If no block near the end of voting has room to close the session,
+        /// the agenda waits for the next session. If no block near the next
+        /// opening has room, the schedule is cleared.
+        fn open_session(club_id: ClubId, now: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            
+            let mut schedule = match SessionSchedules::<T>::get(club_id) {
+                // Ignore openings left behind by a replaced schedule
+                Some(schedule) if schedule.next_session_at == now => schedule,
+                _ => return weight,
+            };
+            
+            let agenda = SessionAgenda::<T>::get(club_id);
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            
+            if !agenda.is_empty() {
+                let session_id = Self::session_count(club_id);
+                let voting_end = now.saturating_add(schedule.voting_period);
+                let (close_at, probes) = Self::first_free_block(voting_end.saturating_add(One::one()), |close_at| {
+                    SessionClosings::<T>::try_mutate(close_at, |sessions| sessions.try_push((club_id, session_id)))
+                        .is_ok()
+                });
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1 + probes, 1));
+                
+                if close_at.is_some() {
+                    SessionAgenda::<T>::remove(club_id);
+                    SessionCount::<T>::insert(club_id, session_id.saturating_add(1));
+                    for &proposal_id in agenda.iter() {
+                        Proposals::<T>::mutate(club_id, proposal_id, |maybe_proposal| {
+                            if let Some(proposal) = maybe_proposal {
+                                proposal.status = ProposalStatus::Active;
+                                proposal.voting_end = voting_end;
+                            }
+                        });
+                        ProposalSessions::<T>::insert(club_id, proposal_id, session_id);
+                    }
+                    weight = weight.saturating_add(
+                        T::DbWeight::get().reads_writes(agenda.len() as u64, 2 * agenda.len() as u64),
+                    );
+                    
+                    Sessions::<T>::insert(club_id, session_id, GovernanceSession {
+                        id: session_id,
+                        club_id,
+                        opened_at: now,
+                        voting_end,
+                        proposals: agenda.clone(),
+                        status: SessionStatus::Voting,
+                    });
+                    weight = weight.saturating_add(T::DbWeight::get().writes(3));
+                    
+                    Self::deposit_event(Event::SessionOpened {
+                        club_id,
+                        session_id,
+                        proposals: agenda.into_inner(),
+                        voting_end,
+                    });
+                } else {
+                    Self::deposit_event(Event::SessionPostponed { club_id });
+                }
+            }
             
+            // Schedule the next session, pushing it back a block at a time if
+            // the target block is already full
+            let (next_session_at, probes) = Self::first_free_block(now.saturating_add(schedule.interval), |at| {
+                SessionOpenings::<T>::try_mutate(at, |clubs| clubs.try_push(club_id)).is_ok()
+            });
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(probes, 1));
+            
+            match next_session_at {
+                Some(next_session_at) => {
+                    schedule.next_session_at = next_session_at;
+                    SessionSchedules::<T>::insert(club_id, schedule);
+                },
+                None => {
+                    SessionSchedules::<T>::remove(club_id);
+                    let released = Self::release_agenda(club_id, now.saturating_add(schedule.voting_period));
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(released, released));
+                    Self::deposit_event(Event::SessionScheduleCleared { club_id });
+                },
+            }
+            weight.saturating_add(T::DbWeight::get().writes(1))
+        }
+        
+        /// Queue an entry at the first block from `from` whose queue accepts it,
+        /// probing at most `MAX_SLOT_PROBES` blocks
+        ///
+        /// Returns the block, if any, and the number of blocks probed.
+        fn first_free_block(
+            from: BlockNumberFor<T>,
+            mut try_queue: impl FnMut(BlockNumberFor<T>) -> bool,
+        ) -> (Option<BlockNumberFor<T>>, u64) {
+            let mut at = from;
+            for probe in 1..=MAX_SLOT_PROBES {
+                if try_queue(at) {
+                    return (Some(at), probe as u64);
+                }
+                at = at.saturating_add(One::one());
+            }
+            (None, MAX_SLOT_PROBES as u64)
+        }
+        
+        /// Open the proposals waiting on a club's agenda for voting on their
+        /// own until `voting_end`, returning how many there were
+        fn release_agenda(club_id: ClubId, voting_end: BlockNumberFor<T>) -> u64 {
+            let agenda = SessionAgenda::<T>::take(club_id);
+            for &proposal_id in agenda.iter() {
+                Proposals::<T>::mutate(club_id, proposal_id, |maybe_proposal| {
+                    if let Some(proposal) = maybe_proposal {
+                        proposal.status = ProposalStatus::Active;
+                        proposal.voting_end = voting_end;
+                    }
+                });
+            }
+            agenda.len() as u64
+        }
+        
+        /// Finalize all proposals of a session together
+        fn close_session(club_id: ClubId, session_id: SessionId) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            
+            let mut session = match Sessions::<T>::get(club_id, session_id) {
+                Some(session) if session.status == SessionStatus::Voting => session,
+                _ => return weight,
+            };
+            
+            let (mut passed, mut rejected, mut expired) = (Vec::new(), Vec::new(), Vec::new());
+            for &proposal_id in session.proposals.iter() {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+                    2,
+                    3 + T::MaxMembersPerClub::get() as u64,
+                ));
+                let proposal = match Proposals::<T>::get(club_id, proposal_id) {
+                    Some(proposal) if proposal.status == ProposalStatus::Active => proposal,
+                    _ => continue,
+                };
+                match Self::conclude(proposal, true) {
+                    ProposalStatus::Passed => passed.push(proposal_id),
+                    ProposalStatus::Rejected => rejected.push(proposal_id),
+                    _ => expired.push(proposal_id),
+                }
+            }
+            
+            session.status = SessionStatus::Finalized;
+            Sessions::<T>::insert(club_id, session_id, &session);
+            
+            Self::deposit_event(Event::SessionFinalized {
+                club_id,
+                session_id,
+                passed,
+                rejected,
+                expired,
+            });
+            
+            weight.saturating_add(T::DbWeight::get().writes(1))
+        }
+        
+        /// Proposals of a club in creation order, optionally filtered by status
+        pub fn proposals_of(
+            club_id: ClubId,
+            status: Option<ProposalStatus>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ProposalInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let mut proposals: Vec<_> = Proposals::<T>::iter_prefix_values(club_id)
+                .filter(|proposal| status.as_ref().map_or(true, |status| proposal.status == *status))
+                .collect();
+            proposals.sort_by_key(|proposal| proposal.id);
+            proposals
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(Self::proposal_info)
+                .collect()
+        }
+        
+        /// A single proposal with its current tally
+        pub fn proposal_details(
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        ) -> Option<ProposalInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            Proposals::<T>::get(club_id, proposal_id).map(Self::proposal_info)
+        }
+        
+        /// Votes cast by `voter` on the proposals of a club
+        pub fn votes_by(
+            club_id: ClubId,
+            voter: &T::AccountId,
+        ) -> Vec<VoteInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+            let mut votes: Vec<_> = Proposals::<T>::iter_key_prefix(club_id)
+                .filter_map(|proposal_id| {
+                    Votes::<T>::get((club_id, proposal_id, voter)).map(|vote| VoteInfo {
+                        proposal_id,
+                        choice: vote.choice,
+                        power: vote.power,
+                        cast_at: vote.cast_at,
+                    })
+                })
+                .collect();
+            votes.sort_by_key(|vote| vote.proposal_id);
+            votes
+        }
+        
+        /// Voting power `who` has on a proposal: the power already cast, the
+        /// power they held when it was created, or zero if they are not eligible
+        pub fn effective_voting_power(
+            club_id: ClubId,
+            proposal_id: ProposalId,
+            who: &T::AccountId,
+        ) -> BalanceOf<T> {
+            if let Some(vote) = Votes::<T>::get((club_id, proposal_id, who)) {
+                return vote.power;
+            }
+            match Proposals::<T>::get(club_id, proposal_id) {
+                Some(proposal) if Self::is_eligible(&proposal, who) => {
+                    Self::voter_power((club_id, proposal_id, who))
+                },
+                _ => Zero::zero(),
+            }
+        }
+        
+        fn proposal_info(proposal: Proposal<T>) -> ProposalInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>> {
+            let decided = proposal.status != ProposalStatus::Active
+                || Self::decided_outcome(&proposal).is_some();
+            // The electorate is released once voting closes, leaving the status
+            // as the only record of the outcome
+            let passing = match proposal.status {
+                ProposalStatus::Active | ProposalStatus::Scheduled => {
+                    Self::passes(&proposal, proposal.aye_votes, proposal.nay_votes, Self::turnout(&proposal))
+                },
+                status => status == ProposalStatus::Passed,
+            };
+            let snapshot = Self::voter_snapshot(proposal.club_id, proposal.id);
+            let tally = ProposalTally {
+                aye_votes: proposal.aye_votes,
+                nay_votes: proposal.nay_votes,
+                abstain_votes: proposal.abstain_votes,
+                passing,
+                decided,
+                eligible_members: snapshot.as_ref().map_or(0, |snapshot| snapshot.eligible_members),
+                eligible_power: snapshot.map_or_else(Zero::zero, |snapshot| snapshot.eligible_power),
+            };
+            ProposalInfo {
+                id: proposal.id,
+                club_id: proposal.club_id,
+                proposer: proposal.proposer,
+                proposal_type: proposal.proposal_type,
+                voting_mechanism: proposal.voting_mechanism,
+                title: proposal.title.into_inner(),
+                summary: proposal.summary.into_inner(),
+                content: proposal.content,
+                deposit: proposal.deposit,
+                created_at: proposal.created_at,
+                voting_end: proposal.voting_end,
+                status: proposal.status,
+                approval_threshold: proposal.approval_threshold,
+                tally,
+            }
+        }
+        
+        /// All revisions of a proposal, oldest first
+        pub fn revisions_of(
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        ) -> Vec<ProposalRevision<T::AccountId, BlockNumberFor<T>>> {
+            let mut revisions: Vec<_> = ProposalRevisions::<T>::iter_prefix_values((club_id, proposal_id))
+                .collect();
+            revisions.sort_by_key(|revision| revision.version);
+            revisions
+        }
+        
+        /// All comments posted on a proposal, oldest first
+        pub fn comments_of(
+            club_id: ClubId,
+            proposal_id: ProposalId,
+        ) -> Vec<ProposalComment<T::AccountId, BlockNumberFor<T>>> {
+            ProposalComments::<T>::get(club_id, proposal_id).into_inner()
+        }
+        
+        /// Discard all votes cast on a proposal, refunding quadratic voting
+        /// costs and the bonds of sealed votes
+        ///
+        /// Only members snapshotted at creation vote, so a proposal holds at
+        /// most `MaxMembersPerClub` votes of each kind.
+        fn reset_votes(proposal: &mut Proposal<T>) {
+            let limit = T::MaxMembersPerClub::get();
+            let commitments = VoteCommitments::<T>::drain_prefix((proposal.club_id, proposal.id))
+                .take(limit as usize);
+            for (voter, commitment) in commitments {
+                T::Currency::unreserve(&voter, commitment.bond);
+            }
+            
+            for (_, vote) in Votes::<T>::drain_prefix((proposal.club_id, proposal.id)).take(limit as usize) {
+                if proposal.voting_mechanism == VotingMechanism::Quadratic {
+                    let cost = Self::multiply_checked(vote.power, vote.power)
+                        .unwrap_or(vote.power);
+                    T::Currency::unreserve(&vote.voter, cost);
+                }
+            }
+            proposal.aye_votes = Zero::zero();
+            proposal.nay_votes = Zero::zero();
+            proposal.abstain_votes = Zero::zero();
+            
+            let _ = OptionVotes::<T>::clear_prefix((proposal.club_id, proposal.id), limit, None);
+            OptionResults::<T>::mutate(proposal.club_id, proposal.id, |results| {
+                results.iter_mut().for_each(|power| *power = Zero::zero())
+            });
+        }
+        
+        /// Whether the given tally passes a proposal
+        ///
+        /// Ayes must outnumber nays and make up the proposal's approval
+        /// threshold of the ayes and nays, and `turnout` must meet the
+        /// quorum. A re-vote overriding a veto needs the guardian council's
+        /// supermajority instead of the approval threshold.
+        fn passes(
+            proposal: &Proposal<T>,
+            aye_votes: BalanceOf<T>,
+            nay_votes: BalanceOf<T>,
+            turnout: BalanceOf<T>,
+        ) -> bool {
+            aye_votes > nay_votes
+                && Self::meets_threshold(
+                    aye_votes,
+                    aye_votes.saturating_add(nay_votes),
+                    Self::approval_threshold(proposal),
+                )
+                && Self::meets_quorum(proposal, turnout)
+        }
+        
+        /// Percentage of the votes a proposal needs: its approval threshold,
+        /// or the guardian council's supermajority on a re-vote overriding a veto
+        fn approval_threshold(proposal: &Proposal<T>) -> u8 {
+            if Vetoes::<T>::contains_key(proposal.club_id, proposal.id) {
+                Self::guardian_council(proposal.club_id)
+                    .map_or(100, |council| council.override_threshold)
+            } else {
+                proposal.approval_threshold
+            }
+        }
+        
+        /// Whether `part` is at least `percent` percent of `whole`
+        fn meets_threshold(part: BalanceOf<T>, whole: BalanceOf<T>, percent: u8) -> bool {
+            part.saturating_mul(100u32.into()) >= whole.saturating_mul(percent.into())
+        }
+        
+        /// Whether `turnout` is at least the quorum of the voting power eligible
+        /// on a proposal
+        fn meets_quorum(proposal: &Proposal<T>, turnout: BalanceOf<T>) -> bool {
+            let eligible_power = Self::voter_snapshot(proposal.club_id, proposal.id)
+                .map_or_else(Zero::zero, |snapshot| snapshot.eligible_power);
+            Self::meets_threshold(turnout, eligible_power, T::Quorum::get())
+        }
+        
+        /// Dispatch `call` with the club origin once `weight_bound` is known
+        /// to cover it, returning its result and actual weight
+        fn dispatch_as_club(
+            club_id: ClubId,
+            call: <T as Config>::RuntimeCall,
+            weight_bound: Weight,
+        ) -> Result<(DispatchResult, Weight), DispatchError> {
+            let info = call.get_dispatch_info();
+            ensure!(info.weight.all_lte(weight_bound), Error::<T>::WrongWeightBound);
+            
+            let result = call.dispatch(RawOrigin::Club(club_id).into());
+            let weight = extract_actual_weight(&result, &info);
+            Ok((result.map(|_| ()).map_err(|e| e.error), weight))
+        }
+        
+        /// Dispatch the action of a passed proposal with the club origin once
+        /// its veto period is over, marking it enacted if the call succeeds
+        fn enact_action(
+            proposal: &Proposal<T>,
+            mut action: ProposalAction<T>,
+            weight_bound: Weight,
+        ) -> Result<(DispatchResult, Weight), DispatchError> {
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                proposal.status == ProposalStatus::Passed
+                    && action.enactable_at.map_or(false, |at| now >= at),
+                Error::<T>::EnactmentNotDue
+            );
+            
+            let call = <T as Config>::RuntimeCall::decode(&mut &action.call[..])
+                .map_err(|_| Error::<T>::InvalidOptionCall)?;
+            
+            let (result, call_weight) = Self::dispatch_as_club(club_id, call, weight_bound)?;
+            if result.is_ok() {
+                action.enacted = true;
+                ProposalActions::<T>::insert(club_id, proposal_id, &action);
+            }
+            Ok((result, call_weight))
+        }
+        
+        /// Weight of noting a body of `len` bytes, which is hashed and stored
+        /// in full up to `MaxNoteSize`, and reserving its deposit
+        pub(crate) fn note_weight(len: usize) -> Weight {
+            let len = len.min(T::MaxNoteSize::get() as usize) as u64;
+            Weight::from_parts(10_000u64.saturating_add(len.saturating_mul(1_000)), len)
+                .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+        }
+        
+        /// Weight of enacting a call that weighed `call_weight`
+        fn enactment_weight(call_weight: Weight) -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(3, 1))
+                .saturating_add(call_weight)
+        }
+        
+        /// Set when the action of a just passed proposal becomes enactable,
+        /// leaving guardians a veto period on large investments
+        fn schedule_enactment(proposal: &Proposal<T>) {
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
+            let mut action = match Self::proposal_action(club_id, proposal_id) {
+                Some(action) => action,
+                None => return,
+            };
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            let veto_period = match (Self::veto(club_id, proposal_id), Self::guardian_council(club_id)) {
+                (Some(mut veto), _) => {
+                    veto.overridden = true;
+                    Vetoes::<T>::insert(club_id, proposal_id, veto);
+                    Self::deposit_event(Event::VetoOverridden { club_id, proposal_id });
+                    Zero::zero()
+                },
+                (None, Some(council))
+                    if proposal.proposal_type == ProposalType::Investment
+                        && action.amount >= council.min_amount => council.veto_period,
+                _ => Zero::zero(),
+            };
+            
+            action.enactable_at = Some(now.saturating_add(veto_period));
+            ProposalActions::<T>::insert(club_id, proposal_id, action);
+        }
+        
+        /// Voting power `who` holds now under the given mechanism, snapshotted
+        /// for each member when a proposal is created
+        pub(crate) fn voting_power_of(who: &T::AccountId, mechanism: &VotingMechanism) -> BalanceOf<T> {
+            let balance = T::Currency::free_balance(who);
+            match mechanism {
+                VotingMechanism::Quadratic => Self::integer_sqrt(balance),
+                _ => balance,
+            }
+        }
+        
+        /// Snapshot the voting power of every member who was in the club when
+        /// the proposal was created, at their current balances
+        ///
+        /// Fails rather than leaving members out of the electorate when the
+        /// club has more than `MaxMembersPerClub` members.
+        pub(crate) fn snapshot_electorate(proposal: &Proposal<T>) -> DispatchResult {
+            let (club_id, proposal_id) = (proposal.club_id, proposal.id);
+            let members = T::Members::members_of(club_id);
+            ensure!(
+                members.len() <= T::MaxMembersPerClub::get() as usize,
+                Error::<T>::TooManyMembers
+            );
+            
+            let mut eligible_members = 0u32;
+            let mut eligible_power = BalanceOf::<T>::zero();
+            for member in members {
+                let eligible = T::Members::is_member(club_id, &member)
+                    && T::Members::joined_at(club_id, &member)
+                        .map_or(false, |joined_at| joined_at <= proposal.created_at);
+                if !eligible {
+                    continue;
+                }
+                let power = Self::voting_power_of(&member, &proposal.voting_mechanism);
+                VoterPowers::<T>::insert((club_id, proposal_id, &member), power);
+                eligible_members = eligible_members.saturating_add(1);
+                eligible_power = eligible_power.saturating_add(power);
+            }
+            VoterSnapshots::<T>::insert(club_id, proposal_id, VoterSnapshot {
+                cutoff: proposal.created_at,
+                eligible_members,
+                eligible_power,
+            });
             Ok(())
         }
-    }
-
-    impl<T: Config> Pallet<T> {
+        
+        /// Remove the electorate snapshot of a proposal that no longer takes votes
+        fn release_electorate(club_id: ClubId, proposal_id: ProposalId) {
+            let _ = VoterPowers::<T>::clear_prefix((club_id, proposal_id), T::MaxMembersPerClub::get(), None);
+            VoterSnapshots::<T>::remove(club_id, proposal_id);
+        }
+        
+        /// Whether `who` may vote on a proposal: only accounts that were club
+        /// members when it was created and are not suspended are eligible
+        fn is_eligible(proposal: &Proposal<T>, who: &T::AccountId) -> bool {
+            T::Members::is_member(proposal.club_id, who)
+                && T::Members::joined_at(proposal.club_id, who)
+                    .map_or(false, |joined_at| joined_at <= proposal.created_at)
+                && VoterPowers::<T>::contains_key((proposal.club_id, proposal.id, who))
+        }
+        
+        /// Total voting power of eligible members who have not yet voted on the proposal
+        ///
+        /// Counts the powers snapshotted at creation, of which there are at most
+        /// `MaxMembersPerClub`.
+        fn uncast_voting_power(proposal: &Proposal<T>) -> BalanceOf<T> {
+            VoterPowers::<T>::iter_prefix((proposal.club_id, proposal.id))
+                .filter(|(member, _)| Self::is_eligible(proposal, member))
+                .filter(|(member, _)| !Votes::<T>::contains_key((proposal.club_id, proposal.id, member)))
+                .fold(Zero::zero(), |total: BalanceOf<T>, (_, power)| total.saturating_add(power))
+        }
+        
+        /// Power of the votes cast on a proposal, abstentions included
+        fn turnout(proposal: &Proposal<T>) -> BalanceOf<T> {
+            proposal.aye_votes
+                .saturating_add(proposal.nay_votes)
+                .saturating_add(proposal.abstain_votes)
+        }
+        
+        /// The outcome of a proposal if it can no longer be changed by the
+        /// voting power that is still uncast, `None` while it is open
+        ///
+        /// It has passed if it would pass even with all uncast power voting
+        /// nay, while the quorum is only met by the votes already cast. It
+        /// has failed if it would not pass even with all uncast power voting aye.
+        fn decided_outcome(proposal: &Proposal<T>) -> Option<bool> {
+            let uncast = Self::uncast_voting_power(proposal);
+            let turnout = Self::turnout(proposal);
+            if Self::passes(proposal, proposal.aye_votes, proposal.nay_votes.saturating_add(uncast), turnout) {
+                Some(true)
+            } else if !Self::passes(
+                proposal,
+                proposal.aye_votes.saturating_add(uncast),
+                proposal.nay_votes,
+                turnout.saturating_add(uncast),
+            ) {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        
         /// Integer square root for quadratic voting
         fn integer_sqrt(n: BalanceOf<T>) -> BalanceOf<T> {
             if n.is_zero() {
@@ -559,5 +3126,11 @@ pub mod pallet {
             a_u128.checked_mul(b_u128).map(|x| x.into())
         }
     }
+
+    /// Club funds moved by calls that clubs dispatch through governance
+    pub trait CallSpend<Call, Balance> {
+        /// Amount of club funds `call` moves, zero if it moves none
+        fn spend_of(call: &Call) -> Balance;
+    }
 }
 
@@ -0,0 +1,157 @@
+//! Storage migrations for the governance pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod v1 {
+    use super::*;
+    use sp_runtime::traits::{Hash, Zero};
+
+    /// Proposal layout with the full body stored inline
+    #[derive(Decode)]
+    pub struct OldProposal<T: Config> {
+        pub id: ProposalId,
+        pub club_id: ClubId,
+        pub proposer: T::AccountId,
+        pub proposal_type: ProposalType,
+        pub voting_mechanism: VotingMechanism,
+        pub title: BoundedVec<u8, ConstU32<256>>,
+        pub description: BoundedVec<u8, ConstU32<4096>>,
+        pub deposit: BalanceOf<T>,
+        pub created_at: BlockNumberFor<T>,
+        pub voting_end: BlockNumberFor<T>,
+        pub status: ProposalStatus,
+        pub aye_votes: BalanceOf<T>,
+        pub nay_votes: BalanceOf<T>,
+        pub abstain_votes: BalanceOf<T>,
+        pub approval_threshold: u8,
+    }
+
+    /// Moves a body formerly stored inline into the note store, returning its
+    /// first 256 bytes, cut at a character boundary, as a summary along with
+    /// a reference to the note
+    ///
+    /// Inline bodies were stored without a deposit, so their notes hold none.
+    pub fn note_body<T: Config>(
+        depositor: &T::AccountId,
+        body: Vec<u8>,
+    ) -> (BoundedVec<u8, ConstU32<256>>, Option<ContentRef>) {
+        let mut end = body.len().min(256);
+        while end > 0 && end < body.len() && body[end] & 0xC0 == 0x80 {
+            end -= 1;
+        }
+        let summary = BoundedVec::truncate_from(body[..end].to_vec());
+
+        let size = body.len() as u32;
+        let data = match BoundedVec::<u8, T::MaxNoteSize>::try_from(body) {
+            Ok(data) if !data.is_empty() => data,
+            _ => return (summary, None),
+        };
+        let hash = T::Hashing::hash(&data);
+        if !Notes::<T>::contains_key(hash) {
+            Notes::<T>::insert(hash, Note {
+                depositor: depositor.clone(),
+                deposit: Zero::zero(),
+                data,
+            });
+        }
+
+        let content = ContentRef {
+            cid: BoundedVec::truncate_from(hash.as_ref().to_vec()),
+            size,
+            mime: MimeTag::PlainText,
+        };
+        (summary, Some(content))
+    }
+
+    /// Moves inline proposal descriptions into the note store, keeping their
+    /// first 256 bytes as a summary
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            Proposals::<T>::translate::<OldProposal<T>, _>(|_, _, old| {
+                translated += 1;
+                let (summary, content) = note_body::<T>(&old.proposer, old.description.into_inner());
+                Some(Proposal {
+                    id: old.id,
+                    club_id: old.club_id,
+                    proposer: old.proposer,
+                    proposal_type: old.proposal_type,
+                    voting_mechanism: old.voting_mechanism,
+                    title: old.title,
+                    summary,
+                    content,
+                    deposit: old.deposit,
+                    created_at: old.created_at,
+                    voting_end: old.voting_end,
+                    status: old.status,
+                    aye_votes: old.aye_votes,
+                    nay_votes: old.nay_votes,
+                    abstain_votes: old.abstain_votes,
+                    approval_threshold: old.approval_threshold,
+                })
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            // Each body is also looked up in and written to the note store
+            T::DbWeight::get().reads_writes(2 * translated + 1, 2 * translated + 1)
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+    use pallet_ikub_members::ClubMembership;
+
+    /// Snapshots the voting power of each eligible member of proposals that
+    /// can still be voted on, at their balances as of the upgrade
+    ///
+    /// Vetoed proposals are snapshotted when a re-vote starts. Proposals of
+    /// clubs too large to snapshot are cancelled rather than voted on by
+    /// part of the club.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let open: Vec<Proposal<T>> = Proposals::<T>::iter_values()
+                .filter(|proposal| {
+                    matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Scheduled)
+                })
+                .collect();
+
+            let mut reads = open.len() as u64;
+            let mut writes = 0u64;
+            for mut proposal in open.into_iter() {
+                let (club_id, proposal_id) = (proposal.club_id, proposal.id);
+                let members = T::Members::members_of(club_id).len() as u64;
+                reads += 1 + 4 * members;
+                writes += 1 + members;
+                if Pallet::<T>::snapshot_electorate(&proposal).is_err() {
+                    proposal.status = ProposalStatus::Cancelled;
+                    Proposals::<T>::insert(club_id, proposal_id, &proposal);
+                    ActiveProposals::<T>::mutate(club_id, |active| active.retain(|&id| id != proposal_id));
+                    SessionAgenda::<T>::mutate(club_id, |agenda| agenda.retain(|&id| id != proposal_id));
+                    writes += 3;
+                }
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+        }
+    }
+}
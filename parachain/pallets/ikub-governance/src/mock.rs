@@ -0,0 +1,174 @@
+use crate as pallet_ikub_governance;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, Randomness},
+    weights::{constants::RocksDbWeight, Weight},
+    PalletId,
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+pub const CLUB: pallet_ikub_governance::ClubId = 0;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const GUARDIAN: AccountId = 10;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        IkubMembers: pallet_ikub_members,
+        IkubTreasury: pallet_ikub_treasury,
+        IkubGovernance: pallet_ikub_governance,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = RocksDbWeight;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl pallet_ikub_members::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxMembersPerClub = ConstU32<10>;
+    type InitialReputation = ConstU64<100>;
+    type MissedContributionPenalty = ConstU64<10>;
+    type MaxMissedContributions = ConstU32<2>;
+}
+
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"ikubtrsy");
+    pub const PayoutWeightBudget: Weight = Weight::from_parts(1_800_000_000, 0);
+    pub static Quorum: u8 = 50;
+}
+
+/// Randomness fixed by the parent block, as relay chain VRF output is
+pub struct ParentBlockRandomness;
+
+impl Randomness<H256, u64> for ParentBlockRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let seed = BlakeTwo256::hash_of(&(subject, System::parent_hash()));
+        (seed, System::block_number().saturating_sub(1))
+    }
+}
+
+impl pallet_ikub_treasury::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MaxSigners = ConstU32<10>;
+    type MinSignatures = ConstU32<2>;
+    type MinContribution = ConstU128<10>;
+    type DefaultContributionPeriod = ConstU64<100>;
+    type GovernanceOrigin = pallet_ikub_governance::EnsureClub<Test>;
+    type TreasuryPalletId = TreasuryPalletId;
+    type WithdrawalExpiry = ConstU64<50>;
+    type MaxExpiringWithdrawals = ConstU32<10>;
+    type MaxExpiringClubsPerBlock = ConstU32<2>;
+    type MaxRoscaParticipants = ConstU32<10>;
+    type ClaimPeriod = ConstU64<20>;
+    type Members = IkubMembers;
+    type PayoutWeightBudget = PayoutWeightBudget;
+    type MaxContributionLots = ConstU32<3>;
+    type MaxClosingsPerBlock = ConstU32<2>;
+    type Randomness = ParentBlockRandomness;
+}
+
+/// Club funds moved by the treasury calls proposals enact
+pub struct ClubSpends;
+
+impl pallet_ikub_governance::CallSpend<RuntimeCall, Balance> for ClubSpends {
+    fn spend_of(call: &RuntimeCall) -> Balance {
+        match call {
+            RuntimeCall::IkubTreasury(pallet_ikub_treasury::Call::distribute_returns { returns, .. }) => *returns,
+            _ => 0,
+        }
+    }
+}
+
+impl pallet_ikub_governance::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MaxProposalsPerClub = ConstU32<10>;
+    type MaxVotingDuration = ConstU64<100>;
+    type MinProposalDeposit = ConstU128<10>;
+    type Members = IkubMembers;
+    type MaxMembersPerClub = ConstU32<10>;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxProposalOptions = ConstU32<4>;
+    type Quorum = Quorum;
+    type Treasury = IkubTreasury;
+    type Spends = ClubSpends;
+    type ClubOrigin = pallet_ikub_governance::EnsureClub<Test>;
+    type MaxGuardians = ConstU32<3>;
+    type MaxCommentsPerProposal = ConstU32<10>;
+    type MaxNoteSize = ConstU32<1024>;
+    type MaxProposalsPerSession = ConstU32<5>;
+    type MaxSessionsPerBlock = ConstU32<2>;
+    type NoteDepositBase = ConstU128<1>;
+    type NoteDepositPerByte = ConstU128<1>;
+}
+
+// Build genesis storage according to the mock runtime, with a club of
+// which Alice, Bob and Charlie are members. Dave holds funds but is not
+// a member.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 500), (CHARLIE, 300), (DAVE, 1_000)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        IkubMembers::create_club(RuntimeOrigin::signed(ALICE), b"club".to_vec(), Vec::new()).unwrap();
+        IkubMembers::join_club(RuntimeOrigin::signed(BOB), CLUB).unwrap();
+        IkubMembers::join_club(RuntimeOrigin::signed(CHARLIE), CLUB).unwrap();
+    });
+    ext
+}
@@ -0,0 +1,568 @@
+use crate::{mock::*, Error, Event, ProposalId, ProposalStatus, ProposalType, RawOrigin, TallyMethod, VoteChoice, VotingMechanism};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::GetDispatchInfo,
+    traits::{Currency, ExistenceRequirement, Hooks, ReservableCurrency},
+    weights::Weight,
+};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn club_origin() -> RuntimeOrigin {
+    RawOrigin::Club(CLUB).into()
+}
+
+// Bob proposes, voting for ten blocks
+fn propose(proposal_type: ProposalType, approval_threshold: u8) -> ProposalId {
+    let proposal_id = IkubGovernance::proposal_count(CLUB);
+    assert_ok!(IkubGovernance::create_proposal(
+        RuntimeOrigin::signed(BOB),
+        CLUB,
+        proposal_type,
+        VotingMechanism::SimpleMajority,
+        b"proposal".to_vec(),
+        b"summary".to_vec(),
+        None,
+        10,
+        approval_threshold,
+    ));
+    proposal_id
+}
+
+fn distribute_returns(returns: Balance) -> RuntimeCall {
+    RuntimeCall::IkubTreasury(pallet_ikub_treasury::Call::distribute_returns { cycle_id: 0, returns })
+}
+
+fn set_guardian_council() {
+    assert_ok!(IkubGovernance::set_guardian_council(club_origin(), vec![GUARDIAN], 50, 5, 10, 75));
+}
+
+fn status_of(proposal_id: ProposalId) -> ProposalStatus {
+    IkubGovernance::proposals(CLUB, proposal_id).unwrap().status
+}
+
+#[test]
+fn votes_count_power_held_at_creation() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+
+        // Bob's deposit is reserved before the snapshot
+        assert_eq!(IkubGovernance::voter_power((CLUB, proposal_id, BOB)), 490);
+        assert_eq!(IkubGovernance::voter_snapshot(CLUB, proposal_id).unwrap().eligible_power, 1_790);
+
+        assert_ok!(<Balances as Currency<AccountId>>::transfer(
+            &ALICE,
+            &CHARLIE,
+            500,
+            ExistenceRequirement::AllowDeath,
+        ));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Nay));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Aye));
+
+        let proposal = IkubGovernance::proposals(CLUB, proposal_id).unwrap();
+        assert_eq!(proposal.aye_votes, 300);
+        assert_eq!(proposal.nay_votes, 1_000);
+    });
+}
+
+#[test]
+fn members_joining_after_creation_cannot_vote() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+
+        assert_ok!(IkubMembers::join_club(RuntimeOrigin::signed(DAVE), CLUB));
+
+        assert_noop!(
+            IkubGovernance::vote(RuntimeOrigin::signed(DAVE), CLUB, proposal_id, VoteChoice::Aye),
+            Error::<Test>::NotEligibleVoter
+        );
+    });
+}
+
+#[test]
+fn electorate_is_released_when_voting_closes() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_eq!(IkubGovernance::voter_snapshot(CLUB, proposal_id), None);
+        assert_eq!(IkubGovernance::voter_power((CLUB, proposal_id, BOB)), 0);
+        assert!(IkubGovernance::proposal_details(CLUB, proposal_id).unwrap().tally.passing);
+    });
+}
+
+#[test]
+fn proposals_need_approval_threshold_and_quorum() {
+    new_test_ext().execute_with(|| {
+        let short_of_threshold = propose(ProposalType::Operational, 80);
+        let approved = propose(ProposalType::Operational, 75);
+        let short_of_quorum = propose(ProposalType::Operational, 50);
+
+        // 1_000 of 1_300 power is about 77%
+        for proposal_id in [short_of_threshold, approved] {
+            assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+            assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Nay));
+        }
+        // Unanimous, but with well under half of the eligible power
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, short_of_quorum, VoteChoice::Aye));
+
+        System::set_block_number(12);
+        for proposal_id in [short_of_threshold, approved, short_of_quorum] {
+            assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+        }
+
+        assert_eq!(status_of(short_of_threshold), ProposalStatus::Rejected);
+        assert_eq!(status_of(approved), ProposalStatus::Passed);
+        assert_eq!(status_of(short_of_quorum), ProposalStatus::Rejected);
+    });
+}
+
+#[test]
+fn proposals_finalize_early_once_the_outcome_is_decided() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+
+        assert_noop!(
+            IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id),
+            Error::<Test>::OutcomeNotDecided
+        );
+
+        // Bob's and Charlie's 790 can no longer outvote Alice's 1_000
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_eq!(status_of(proposal_id), ProposalStatus::Passed);
+        System::assert_last_event(
+            Event::<Test>::ProposalFinalizedEarly { club_id: CLUB, proposal_id, passed: true }.into(),
+        );
+    });
+}
+
+#[test]
+fn proposals_only_finalize_early_once_the_votes_cast_meet_the_quorum() {
+    new_test_ext().execute_with(|| {
+        Quorum::set(60);
+        let proposal_id = propose(ProposalType::Operational, 50);
+
+        // Alice's 1_000 outvotes everyone else but is short of 60% of 1_790
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_noop!(
+            IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id),
+            Error::<Test>::OutcomeNotDecided
+        );
+
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_eq!(status_of(proposal_id), ProposalStatus::Passed);
+    });
+}
+
+#[test]
+fn sessions_finalize_their_proposals_together() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubGovernance::set_session_schedule(RuntimeOrigin::signed(ALICE), CLUB, 5, 3));
+        let invest = propose(ProposalType::Operational, 50);
+        let hold = propose(ProposalType::Operational, 50);
+        assert_eq!(status_of(invest), ProposalStatus::Scheduled);
+
+        // Proposals wait on the agenda for the session opening at block 6
+        System::set_block_number(6);
+        IkubGovernance::on_initialize(6);
+        System::assert_last_event(
+            Event::<Test>::SessionOpened { club_id: CLUB, session_id: 0, proposals: vec![invest, hold], voting_end: 9 }
+                .into(),
+        );
+
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, invest, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, hold, VoteChoice::Nay));
+        assert_noop!(
+            IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, invest),
+            Error::<Test>::ProposalInSession
+        );
+
+        System::set_block_number(10);
+        IkubGovernance::on_initialize(10);
+        System::assert_last_event(
+            Event::<Test>::SessionFinalized {
+                club_id: CLUB,
+                session_id: 0,
+                passed: vec![invest],
+                rejected: vec![hold],
+                expired: vec![],
+            }
+            .into(),
+        );
+        assert_eq!(status_of(invest), ProposalStatus::Passed);
+        assert_eq!(status_of(hold), ProposalStatus::Rejected);
+    });
+}
+
+#[test]
+fn sealed_votes_only_reveal_for_their_voter() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubGovernance::set_secret_ballot_bond(RuntimeOrigin::signed(ALICE), CLUB, 20));
+        let proposal_id = propose(ProposalType::Operational, 50);
+        assert_ok!(IkubGovernance::enable_secret_ballot(RuntimeOrigin::signed(BOB), CLUB, proposal_id, 5));
+
+        let salt = [7u8; 32];
+        let commitment = BlakeTwo256::hash_of(&(ALICE, proposal_id, VoteChoice::Aye, salt));
+        assert_ok!(IkubGovernance::commit_vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, commitment));
+        // Charlie copies Alice's sealed vote
+        assert_ok!(IkubGovernance::commit_vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, commitment));
+        assert_noop!(
+            IkubGovernance::vote(RuntimeOrigin::signed(BOB), CLUB, proposal_id, VoteChoice::Nay),
+            Error::<Test>::SecretBallot
+        );
+        assert_eq!(Balances::reserved_balance(ALICE), 20);
+
+        System::set_block_number(12);
+        assert_ok!(IkubGovernance::reveal_vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye, salt));
+        assert_noop!(
+            IkubGovernance::reveal_vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Aye, salt),
+            Error::<Test>::InvalidReveal
+        );
+
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(IkubGovernance::proposals(CLUB, proposal_id).unwrap().aye_votes, 1_000);
+    });
+}
+
+#[test]
+fn unrevealed_bonds_go_to_the_treasury() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubGovernance::set_secret_ballot_bond(RuntimeOrigin::signed(ALICE), CLUB, 20));
+        let proposal_id = propose(ProposalType::Operational, 50);
+        assert_ok!(IkubGovernance::enable_secret_ballot(RuntimeOrigin::signed(BOB), CLUB, proposal_id, 5));
+
+        let salt = [7u8; 32];
+        let commitment = BlakeTwo256::hash_of(&(ALICE, proposal_id, VoteChoice::Aye, salt));
+        assert_ok!(IkubGovernance::commit_vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, commitment));
+        let commitment = BlakeTwo256::hash_of(&(CHARLIE, proposal_id, VoteChoice::Nay, salt));
+        assert_ok!(IkubGovernance::commit_vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, commitment));
+
+        System::set_block_number(12);
+        assert_ok!(IkubGovernance::reveal_vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye, salt));
+        assert_noop!(
+            IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id),
+            Error::<Test>::RevealPhaseNotEnded
+        );
+
+        System::set_block_number(17);
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_eq!(status_of(proposal_id), ProposalStatus::Passed);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 20);
+        assert_eq!(Balances::free_balance(CHARLIE), 280);
+        System::assert_has_event(
+            Event::<Test>::UnrevealedVotePenalized { club_id: CLUB, proposal_id, voter: CHARLIE, amount: 20 }.into(),
+        );
+    });
+}
+
+#[test]
+fn resetting_votes_refunds_sealed_votes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubGovernance::set_secret_ballot_bond(RuntimeOrigin::signed(ALICE), CLUB, 20));
+        let proposal_id = propose(ProposalType::Operational, 50);
+        assert_ok!(IkubGovernance::enable_secret_ballot(RuntimeOrigin::signed(BOB), CLUB, proposal_id, 5));
+
+        let commitment = BlakeTwo256::hash_of(&(ALICE, proposal_id, VoteChoice::Aye, [7u8; 32]));
+        assert_ok!(IkubGovernance::commit_vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, commitment));
+
+        assert_noop!(
+            IkubGovernance::amend_proposal(RuntimeOrigin::signed(BOB), CLUB, proposal_id, [1u8; 32], b"v2".to_vec(), false),
+            Error::<Test>::VotesAlreadyCast
+        );
+        assert_ok!(IkubGovernance::amend_proposal(
+            RuntimeOrigin::signed(BOB),
+            CLUB,
+            proposal_id,
+            [1u8; 32],
+            b"v2".to_vec(),
+            true,
+        ));
+
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert!(IkubGovernance::vote_commitments((CLUB, proposal_id, ALICE)).is_none());
+    });
+}
+
+#[test]
+fn revisions_reset_the_votes_cast() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Aye));
+
+        assert_ok!(IkubGovernance::amend_proposal(
+            RuntimeOrigin::signed(BOB),
+            CLUB,
+            proposal_id,
+            [1u8; 32],
+            b"v2".to_vec(),
+            true,
+        ));
+        System::assert_last_event(
+            Event::<Test>::ProposalAmended { club_id: CLUB, proposal_id, version: 1, votes_reset: true }.into(),
+        );
+
+        let proposal = IkubGovernance::proposals(CLUB, proposal_id).unwrap();
+        assert_eq!(proposal.aye_votes, 0);
+        assert!(IkubGovernance::votes((CLUB, proposal_id, CHARLIE)).is_none());
+
+        // Members vote again on the revised proposal
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Nay));
+        assert_eq!(IkubGovernance::proposals(CLUB, proposal_id).unwrap().nay_votes, 300);
+    });
+}
+
+#[test]
+fn comments_are_bounded_per_proposal() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+
+        assert_noop!(
+            IkubGovernance::post_comment(RuntimeOrigin::signed(DAVE), CLUB, proposal_id, [1u8; 32]),
+            Error::<Test>::NotClubMember
+        );
+        for _ in 0..10 {
+            assert_ok!(IkubGovernance::post_comment(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, [1u8; 32]));
+        }
+        assert_noop!(
+            IkubGovernance::post_comment(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, [2u8; 32]),
+            Error::<Test>::MaxCommentsExceeded
+        );
+    });
+}
+
+#[test]
+fn notes_reserve_a_deposit_until_unnoted() {
+    new_test_ext().execute_with(|| {
+        let data = b"evidence".to_vec();
+        let hash = BlakeTwo256::hash(&data);
+        assert_ok!(IkubGovernance::note_content(RuntimeOrigin::signed(DAVE), data.clone()));
+
+        // One unit base deposit and one per byte
+        assert_eq!(Balances::reserved_balance(DAVE), 9);
+        assert_eq!(IkubGovernance::notes(hash).unwrap().data.to_vec(), data);
+        assert_noop!(
+            IkubGovernance::note_content(RuntimeOrigin::signed(ALICE), data),
+            Error::<Test>::AlreadyNoted
+        );
+        assert_noop!(
+            IkubGovernance::unnote_content(RuntimeOrigin::signed(ALICE), hash),
+            Error::<Test>::NotNoteDepositor
+        );
+
+        assert_ok!(IkubGovernance::unnote_content(RuntimeOrigin::signed(DAVE), hash));
+        assert_eq!(Balances::reserved_balance(DAVE), 0);
+        assert!(IkubGovernance::notes(hash).is_none());
+    });
+}
+
+#[test]
+fn actions_are_priced_from_their_call_and_frozen_once_voted() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Investment, 50);
+        let call = distribute_returns(100).encode();
+
+        assert_noop!(
+            IkubGovernance::set_proposal_action(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, call.clone()),
+            Error::<Test>::NotProposer
+        );
+        assert_ok!(IkubGovernance::set_proposal_action(RuntimeOrigin::signed(BOB), CLUB, proposal_id, call.clone()));
+        assert_eq!(IkubGovernance::proposal_action(CLUB, proposal_id).unwrap().amount, 100);
+
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_noop!(
+            IkubGovernance::set_proposal_action(RuntimeOrigin::signed(BOB), CLUB, proposal_id, distribute_returns(1).encode()),
+            Error::<Test>::VotesAlreadyCast
+        );
+    });
+}
+
+#[test]
+fn guardians_veto_large_investments_until_members_override() {
+    new_test_ext().execute_with(|| {
+        set_guardian_council();
+        let proposal_id = propose(ProposalType::Investment, 50);
+        assert_ok!(IkubGovernance::set_proposal_action(
+            RuntimeOrigin::signed(BOB),
+            CLUB,
+            proposal_id,
+            distribute_returns(100).encode(),
+        ));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        let call_weight = distribute_returns(100).get_dispatch_info().weight;
+        assert_eq!(IkubGovernance::proposal_action(CLUB, proposal_id).unwrap().enactable_at, Some(6));
+        assert_noop!(
+            IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight),
+            Error::<Test>::EnactmentNotDue
+        );
+
+        assert_noop!(
+            IkubGovernance::veto_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, [2u8; 32]),
+            Error::<Test>::NotGuardian
+        );
+        assert_ok!(IkubGovernance::veto_proposal(RuntimeOrigin::signed(GUARDIAN), CLUB, proposal_id, [2u8; 32]));
+        assert_eq!(status_of(proposal_id), ProposalStatus::Vetoed);
+
+        System::set_block_number(6);
+        assert_noop!(
+            IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight),
+            Error::<Test>::EnactmentNotDue
+        );
+
+        // The re-vote needs the council's 75% rather than a simple majority
+        assert_ok!(IkubGovernance::override_veto(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(CHARLIE), CLUB, proposal_id, VoteChoice::Nay));
+        assert_noop!(
+            IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id),
+            Error::<Test>::OutcomeNotDecided
+        );
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(BOB), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_eq!(status_of(proposal_id), ProposalStatus::Passed);
+        assert!(IkubGovernance::veto(CLUB, proposal_id).unwrap().overridden);
+        assert_eq!(IkubGovernance::proposal_action(CLUB, proposal_id).unwrap().enactable_at, Some(6));
+        assert_noop!(
+            IkubGovernance::veto_proposal(RuntimeOrigin::signed(GUARDIAN), CLUB, proposal_id, [2u8; 32]),
+            Error::<Test>::NotVetoable
+        );
+    });
+}
+
+#[test]
+fn passed_actions_are_enacted_with_the_club_origin() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+        let call = RuntimeCall::IkubGovernance(crate::Call::set_guardian_council {
+            guardians: vec![GUARDIAN],
+            min_amount: 50,
+            veto_period: 5,
+            override_period: 10,
+            override_threshold: 75,
+        });
+        assert_ok!(IkubGovernance::set_proposal_action(RuntimeOrigin::signed(BOB), CLUB, proposal_id, call.encode()));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        let call_weight = call.get_dispatch_info().weight;
+        assert_ok!(IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight));
+
+        assert!(IkubGovernance::proposal_action(CLUB, proposal_id).unwrap().enacted);
+        assert_eq!(IkubGovernance::guardian_council(CLUB).unwrap().guardians.into_inner(), vec![GUARDIAN]);
+        assert_noop!(
+            IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight),
+            Error::<Test>::AlreadyEnacted
+        );
+    });
+}
+
+#[test]
+fn failed_enactments_can_be_retried() {
+    new_test_ext().execute_with(|| {
+        let proposal_id = propose(ProposalType::Operational, 50);
+        // There is no contribution cycle to distribute returns to
+        let call = distribute_returns(100);
+        assert_ok!(IkubGovernance::set_proposal_action(RuntimeOrigin::signed(BOB), CLUB, proposal_id, call.encode()));
+        assert_ok!(IkubGovernance::vote(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, VoteChoice::Aye));
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id));
+
+        assert_noop!(
+            IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, Weight::zero()),
+            Error::<Test>::WrongWeightBound
+        );
+
+        let call_weight = call.get_dispatch_info().weight;
+        assert_ok!(IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight));
+        System::assert_last_event(
+            Event::<Test>::ProposalEnacted {
+                club_id: CLUB,
+                proposal_id,
+                result: Err(pallet_ikub_treasury::Error::<Test>::CycleNotFound.into()),
+            }
+            .into(),
+        );
+
+        assert!(!IkubGovernance::proposal_action(CLUB, proposal_id).unwrap().enacted);
+        assert_ok!(IkubGovernance::enact_proposal(RuntimeOrigin::signed(ALICE), CLUB, proposal_id, call_weight));
+    });
+}
+
+#[test]
+fn winning_options_wait_for_the_veto_period() {
+    new_test_ext().execute_with(|| {
+        set_guardian_council();
+        assert_ok!(IkubGovernance::create_multi_option_proposal(
+            RuntimeOrigin::signed(BOB),
+            CLUB,
+            ProposalType::Investment,
+            b"proposal".to_vec(),
+            b"summary".to_vec(),
+            None,
+            10,
+            60,
+            TallyMethod::Plurality,
+            vec![(b"invest".to_vec(), Some(distribute_returns(100).encode())), (b"hold".to_vec(), None)],
+        ));
+
+        // 1_000 of 1_490 power is about 67%
+        assert_ok!(IkubGovernance::vote_options(RuntimeOrigin::signed(ALICE), CLUB, 0, vec![0]));
+        assert_ok!(IkubGovernance::vote_options(RuntimeOrigin::signed(BOB), CLUB, 0, vec![1]));
+
+        System::set_block_number(12);
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(status_of(0), ProposalStatus::Passed);
+        assert_eq!(IkubGovernance::winning_option(CLUB, 0).unwrap().index, 0);
+        let action = IkubGovernance::proposal_action(CLUB, 0).unwrap();
+        assert_eq!(action.amount, 100);
+        assert_eq!(action.enactable_at, Some(17));
+
+        let call_weight = distribute_returns(100).get_dispatch_info().weight;
+        assert_noop!(
+            IkubGovernance::enact_option(RuntimeOrigin::signed(ALICE), CLUB, 0, call_weight),
+            Error::<Test>::EnactmentNotDue
+        );
+        assert_ok!(IkubGovernance::veto_proposal(RuntimeOrigin::signed(GUARDIAN), CLUB, 0, [2u8; 32]));
+
+        System::set_block_number(17);
+        assert_noop!(
+            IkubGovernance::enact_option(RuntimeOrigin::signed(ALICE), CLUB, 0, call_weight),
+            Error::<Test>::EnactmentNotDue
+        );
+    });
+}
+
+#[test]
+fn winning_options_need_the_approval_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubGovernance::create_multi_option_proposal(
+            RuntimeOrigin::signed(BOB),
+            CLUB,
+            ProposalType::Operational,
+            b"proposal".to_vec(),
+            b"summary".to_vec(),
+            None,
+            10,
+            70,
+            TallyMethod::Plurality,
+            vec![(b"invest".to_vec(), None), (b"hold".to_vec(), None)],
+        ));
+
+        assert_ok!(IkubGovernance::vote_options(RuntimeOrigin::signed(ALICE), CLUB, 0, vec![0]));
+        assert_ok!(IkubGovernance::vote_options(RuntimeOrigin::signed(BOB), CLUB, 0, vec![1]));
+
+        System::set_block_number(12);
+        assert_ok!(IkubGovernance::finalize_proposal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(status_of(0), ProposalStatus::Rejected);
+        assert!(IkubGovernance::winning_option(CLUB, 0).is_none());
+    });
+}
@@ -2,6 +2,8 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
@@ -14,10 +16,23 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         #[pallet::constant]
         type MaxMembersPerClub: Get<u32>;
+        /// Reputation members start with
+        #[pallet::constant]
+        type InitialReputation: Get<ReputationScore>;
+        /// Reputation lost for each missed contribution
+        #[pallet::constant]
+        type MissedContributionPenalty: Get<ReputationScore>;
+        /// Missed contributions after which a member is suspended
+        #[pallet::constant]
+        type MaxMissedContributions: Get<u32>;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     pub type ClubId = u64;
@@ -84,6 +99,19 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Storage: Members suspended from club activities
+    #[pallet::storage]
+    #[pallet::getter(fn suspended)]
+    pub type Suspended<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -105,6 +133,14 @@ pub mod pallet {
             account: T::AccountId,
             new_reputation: ReputationScore,
         },
+        MemberSuspended {
+            club_id: ClubId,
+            account: T::AccountId,
+        },
+        MemberReinstated {
+            club_id: ClubId,
+            account: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -115,6 +151,8 @@ pub mod pallet {
         ClubNotFound,
         InvalidClubName,
         InvalidClubDescription,
+        NotClubAdmin,
+        NotSuspended,
     }
 
     #[pallet::call]
@@ -161,7 +199,7 @@ pub mod pallet {
                 account: creator.clone(),
                 club_id,
                 joined_at: now,
-                reputation: 0,
+                reputation: T::InitialReputation::get(),
                 contribution_weight: 0,
                 voting_participation: 0,
                 proposal_success_rate: 0,
@@ -215,7 +253,7 @@ pub mod pallet {
                 account: account.clone(),
                 club_id,
                 joined_at: now,
-                reputation: 0,
+                reputation: T::InitialReputation::get(),
                 contribution_weight: 0,
                 voting_participation: 0,
                 proposal_success_rate: 0,
@@ -233,6 +271,9 @@ pub mod pallet {
         }
 
         /// Leave a club
+        ///
+        /// A suspension outlasts the membership, so suspended members who
+        /// leave and rejoin stay suspended until reinstated.
         #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
         #[pallet::call_index(2)]
         pub fn leave_club(
@@ -257,6 +298,33 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Lift the suspension of a member
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(3)]
+        pub fn reinstate_member(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            let admin = ensure_signed(origin)?;
+            
+            ensure!(
+                <Self as ClubMembership<_, _>>::is_admin(club_id, &admin),
+                Error::<T>::NotClubAdmin
+            );
+            ensure!(
+                Suspended::<T>::take(club_id, &account).is_some(),
+                Error::<T>::NotSuspended
+            );
+            
+            Self::deposit_event(Event::MemberReinstated {
+                club_id,
+                account,
+            });
+            
+            Ok(())
+        }
+
         /// Update member reputation (called by other pallets)
         pub fn update_reputation(
             club_id: ClubId,
@@ -276,5 +344,65 @@ pub mod pallet {
             Ok(())
         }
     }
-}
 
+    /// Read-only view of club membership for use by other pallets
+    pub trait ClubMembership<AccountId, BlockNumber> {
+        /// Whether `who` is a member of the club and not suspended
+        fn is_member(club_id: ClubId, who: &AccountId) -> bool;
+        /// Block at which `who` joined the club, if they are a member
+        fn joined_at(club_id: ClubId, who: &AccountId) -> Option<BlockNumber>;
+        /// All current members of the club
+        fn members_of(club_id: ClubId) -> Vec<AccountId>;
+        /// Whether `who` administers the club
+        fn is_admin(club_id: ClubId, who: &AccountId) -> bool;
+    }
+
+    impl<T: Config> ClubMembership<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn is_member(club_id: ClubId, who: &T::AccountId) -> bool {
+            Members::<T>::contains_key(club_id, who) && !Suspended::<T>::contains_key(club_id, who)
+        }
+
+        fn joined_at(club_id: ClubId, who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+            Members::<T>::get(club_id, who).map(|profile| profile.joined_at)
+        }
+
+        fn members_of(club_id: ClubId) -> Vec<T::AccountId> {
+            Members::<T>::iter_key_prefix(club_id).collect()
+        }
+
+        fn is_admin(club_id: ClubId, who: &T::AccountId) -> bool {
+            Clubs::<T>::get(club_id).map_or(false, |club| club.creator == *who)
+        }
+    }
+
+    /// Consequences of members failing their obligations to a club
+    pub trait MemberStanding<AccountId> {
+        /// `who` missed a contribution; `misses` counts all their misses in the club
+        fn note_missed_contribution(club_id: ClubId, who: &AccountId, misses: u32);
+    }
+
+    impl<T: Config> MemberStanding<T::AccountId> for Pallet<T> {
+        fn note_missed_contribution(club_id: ClubId, who: &T::AccountId, misses: u32) {
+            let mut profile = match Members::<T>::get(club_id, who) {
+                Some(profile) => profile,
+                None => return,
+            };
+            profile.reputation = profile.reputation.saturating_sub(T::MissedContributionPenalty::get());
+            Members::<T>::insert(club_id, who, &profile);
+            
+            Self::deposit_event(Event::ReputationUpdated {
+                club_id,
+                account: who.clone(),
+                new_reputation: profile.reputation,
+            });
+            
+            if misses >= T::MaxMissedContributions::get() && !Suspended::<T>::contains_key(club_id, who) {
+                Suspended::<T>::insert(club_id, who, <frame_system::Pallet<T>>::block_number());
+                Self::deposit_event(Event::MemberSuspended {
+                    club_id,
+                    account: who.clone(),
+                });
+            }
+        }
+    }
+}
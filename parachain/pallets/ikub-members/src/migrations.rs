@@ -0,0 +1,37 @@
+//! Storage migrations for the members pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+    use sp_runtime::Saturating;
+
+    /// Raises the reputation of existing members by the initial reputation
+    ///
+    /// Members used to start at zero, so penalties for missed contributions
+    /// had nothing to take away.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            Members::<T>::translate::<MemberProfile<T>, _>(|_, _, mut profile| {
+                translated += 1;
+                profile.reputation = profile.reputation.saturating_add(T::InitialReputation::get());
+                Some(profile)
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+    }
+}
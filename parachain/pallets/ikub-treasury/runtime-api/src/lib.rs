@@ -0,0 +1,24 @@
+//! Runtime API definition for the IkubChain treasury pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_ikub_treasury::{ClubId, MemberEquity};
+
+sp_api::decl_runtime_apis! {
+    /// Queries over club unit accounting
+    pub trait TreasuryApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Treasury funds that are not earmarked plus the value of the club's positions
+        fn net_asset_value(club_id: ClubId) -> Balance;
+
+        /// Club units in issue
+        fn total_units(club_id: ClubId) -> Balance;
+
+        /// Units an account holds in a club and their value at the current net asset value
+        fn member_equity(club_id: ClubId, who: AccountId) -> MemberEquity<Balance>;
+    }
+}
@@ -2,16 +2,33 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, ReservableCurrency, Time},
+        storage::IterableStorageDoubleMap,
+        traits::{Currency, EnsureOrigin, ExistenceRequirement, Randomness, ReservableCurrency, Time},
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero, SaturatedConversion};
+    use sp_runtime::{
+        helpers_128bit::multiply_by_rational_with_rounding,
+        traits::{AccountIdConversion, Hash, One, Saturating, Zero, SaturatedConversion},
+        Rounding,
+    };
     use scale_info::TypeInfo;
-    use codec::{Decode, Encode, MaxEncodedLen};
+    use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+    use pallet_ikub_members::{ClubMembership, MemberStanding};
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
@@ -37,10 +54,62 @@ pub mod pallet {
         /// Default contribution period in blocks
         #[pallet::constant]
         type DefaultContributionPeriod: Get<BlockNumberFor<Self>>;
+        
+        /// Origin of a club acting through its governance, used to manage signer sets
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ClubId>;
+        
+        /// Pallet ID from which club treasury accounts are derived
+        #[pallet::constant]
+        type TreasuryPalletId: Get<PalletId>;
+        
+        /// Blocks after which a withdrawal request that is still pending expires
+        #[pallet::constant]
+        type WithdrawalExpiry: Get<BlockNumberFor<Self>>;
+        
+        /// Maximum number of withdrawal requests of one club expiring in one block
+        #[pallet::constant]
+        type MaxExpiringWithdrawals: Get<u32>;
+        
+        /// Maximum number of clubs whose withdrawal requests expire in one
+        /// block; requests of further clubs expire in the following blocks
+        #[pallet::constant]
+        type MaxExpiringClubsPerBlock: Get<u32>;
+        
+        /// Maximum number of participants in a rotating savings group
+        #[pallet::constant]
+        type MaxRoscaParticipants: Get<u32>;
+        
+        /// Blocks after distribution during which returns can be claimed
+        #[pallet::constant]
+        type ClaimPeriod: Get<BlockNumberFor<Self>>;
+        
+        /// Club membership, informed when members miss contributions
+        type Members: ClubMembership<Self::AccountId, BlockNumberFor<Self>> + MemberStanding<Self::AccountId>;
+        
+        /// Weight each block may spend paying out returns
+        #[pallet::constant]
+        type PayoutWeightBudget: Get<Weight>;
+        
+        /// Maximum number of separate contributions a member makes to one cycle
+        #[pallet::constant]
+        type MaxContributionLots: Get<u32>;
+        
+        /// Maximum number of contribution cycles closed in one block; cycles
+        /// beyond it close in the following blocks
+        #[pallet::constant]
+        type MaxClosingsPerBlock: Get<u32>;
+        
+        /// Source of the lottery draws of rotating savings groups, such as
+        /// relay chain VRF output
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Type aliases
@@ -48,6 +117,9 @@ pub mod pallet {
     pub type ClubId = u64;
     pub type WithdrawalId = u64;
     pub type ContributionCycleId = u64;
+    pub type SignerSetVersion = u32;
+    pub type RoscaId = u64;
+    pub type RoundIndex = u32;
 
     /// Withdrawal status
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -56,6 +128,7 @@ pub mod pallet {
         Approved,
         Executed,
         Cancelled,
+        Rejected,
     }
 
     /// Withdrawal request
@@ -64,20 +137,101 @@ pub mod pallet {
     pub struct WithdrawalRequest<T: Config> {
         pub id: WithdrawalId,
         pub club_id: ClubId,
+        pub requester: T::AccountId,
         pub recipient: T::AccountId,
         pub amount: BalanceOf<T>,
         pub unlock_at: BlockNumberFor<T>,
         pub status: WithdrawalStatus,
         pub signatures: BoundedVec<T::AccountId, T::MaxSigners>,
+        /// Signers who rejected the request
+        pub rejections: BoundedVec<T::AccountId, T::MaxSigners>,
+        /// Signer set version the signatures were collected under
+        pub signer_set_version: SignerSetVersion,
         pub created_at: BlockNumberFor<T>,
     }
 
+    /// Accounts authorized to request and approve withdrawals of a club
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct SignerSet<T: Config> {
+        pub signers: BoundedVec<T::AccountId, T::MaxSigners>,
+        /// Signatures needed to approve a withdrawal
+        pub threshold: u32,
+        /// Bumped on every change, invalidating signatures collected earlier
+        pub version: SignerSetVersion,
+    }
+
+    /// Spending limits protecting a club treasury
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct SpendingLimit<T: Config> {
+        /// Largest amount a single withdrawal may move
+        pub max_per_withdrawal: BalanceOf<T>,
+        /// Largest total amount that may leave the treasury within one period
+        pub period_cap: BalanceOf<T>,
+        /// Length of a spending period in blocks
+        pub period: BlockNumberFor<T>,
+    }
+
+    /// How the recipient of each ROSCA round is chosen
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum PayoutOrder {
+        /// Participants receive in the order they were listed
+        Fixed,
+        /// A random participant who has not received yet, drawn from the
+        /// hash of the round's last block
+        Lottery,
+        /// The participant accepting the largest discount on the pot
+        Bidding,
+    }
+
+    /// ROSCA status
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum RoscaStatus {
+        Active,
+        Completed,
+    }
+
+    /// Rotating savings group: every round each participant pays a fixed
+    /// contribution and one participant receives the pot
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Rosca<T: Config> {
+        pub id: RoscaId,
+        pub club_id: ClubId,
+        /// Amount each participant pays per round
+        pub contribution: BalanceOf<T>,
+        pub round_length: BlockNumberFor<T>,
+        pub order: PayoutOrder,
+        pub participants: BoundedVec<T::AccountId, T::MaxRoscaParticipants>,
+        pub round: RoundIndex,
+        pub round_start: BlockNumberFor<T>,
+        /// Contributions collected and not paid out yet
+        pub pot: BalanceOf<T>,
+        /// Number of participants who received a pot
+        pub paid_out: u32,
+        pub status: RoscaStatus,
+    }
+
     /// Contribution cycle status
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
     pub enum CycleStatus {
         Open,
         Closed,
         Distributed,
+        /// Claim deadline passed and unclaimed returns went back to the treasury
+        Swept,
+    }
+
+    /// How the returns of a cycle are shared among its contributors
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub enum ShareMode {
+        /// In proportion to the amount contributed
+        #[default]
+        ProRata,
+        /// In proportion to the amount contributed times the blocks left in
+        /// the cycle when it was contributed
+        TimeWeighted,
     }
 
     /// Contribution cycle
@@ -92,6 +246,10 @@ pub mod pallet {
         pub returns: BalanceOf<T>,
         pub status: CycleStatus,
         pub minimum_contribution: BalanceOf<T>,
+        /// Returns claimed so far
+        pub claimed_returns: BalanceOf<T>,
+        /// Last block at which returns can be claimed, set on distribution
+        pub claim_deadline: Option<BlockNumberFor<T>>,
     }
 
     /// Individual contribution record
@@ -99,11 +257,56 @@ pub mod pallet {
     #[scale_info(skip_type_params(T))]
     pub struct Contribution<T: Config> {
         pub contributor: T::AccountId,
+        pub amount: BalanceOf<T>,
+        /// Block of the first contribution; each top-up is kept in [`ContributionLots`]
+        pub contributed_at: BlockNumberFor<T>,
+    }
+
+    /// A single contribution of a member to a cycle
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ContributionLot<T: Config> {
         pub amount: BalanceOf<T>,
         pub contributed_at: BlockNumberFor<T>,
     }
 
+    /// Terms for contributions paid after a cycle's end block
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PaymentTerms<T: Config> {
+        /// Blocks after the end block during which contributions are still accepted
+        pub grace_period: BlockNumberFor<T>,
+        /// Fee paid into the treasury for each contribution made in the grace period
+        pub late_fee: BalanceOf<T>,
+    }
+
+    /// A member's stake in a club as served to clients through the runtime API
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct MemberEquity<Balance> {
+        /// Club units held by the member
+        pub units: Balance,
+        /// Value of those units at the club's current net asset value
+        pub value: Balance,
+    }
+
+    /// Standing authorization for the treasury to pull a member's
+    /// contribution every cycle
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ContributionMandate<T: Config> {
+        /// Amount pulled per cycle
+        pub amount: BalanceOf<T>,
+        /// Total amount the mandate may pull over its lifetime
+        pub cap: BalanceOf<T>,
+        /// Total amount pulled so far
+        pub collected: BalanceOf<T>,
+    }
+
     /// Storage: Treasury balances per club
+    ///
+    /// Mirrors the free balance of the club's treasury account, which is the
+    /// source of truth; see [`Pallet::reconcile`].
     #[pallet::storage]
     #[pallet::getter(fn treasury_balance)]
     pub type TreasuryBalances<T: Config> = StorageMap<
@@ -114,6 +317,61 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Storage: Treasury funds committed to open withdrawal requests per club
+    #[pallet::storage]
+    #[pallet::getter(fn earmarked)]
+    pub type Earmarked<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Withdrawal signer set per club
+    #[pallet::storage]
+    #[pallet::getter(fn signer_set)]
+    pub type ClubSigners<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        SignerSet<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Spending limits per club
+    #[pallet::storage]
+    #[pallet::getter(fn spending_limit)]
+    pub type SpendingLimits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        SpendingLimit<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Start of the current spending period and the amount spent in it
+    #[pallet::storage]
+    #[pallet::getter(fn spending_window)]
+    pub type SpendingWindows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        (BlockNumberFor<T>, BalanceOf<T>),
+        ValueQuery,
+    >;
+
+    /// Storage: Amount governance allowed to be spent beyond the spending limits
+    #[pallet::storage]
+    #[pallet::getter(fn spending_override)]
+    pub type SpendingOverrides<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
     /// Storage: Withdrawal requests
     #[pallet::storage]
     #[pallet::getter(fn withdrawal_requests)]
@@ -138,6 +396,23 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Storage: Withdrawal requests of a club expiring at a block
+    #[pallet::storage]
+    pub type WithdrawalExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        ClubId,
+        BoundedVec<WithdrawalId, T::MaxExpiringWithdrawals>,
+        ValueQuery,
+    >;
+
+    /// Storage: First block whose withdrawal expiries have not all been
+    /// handled, if expiries fell behind
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     /// Storage: Contribution cycles
     #[pallet::storage]
     #[pallet::getter(fn contribution_cycles)]
@@ -173,6 +448,84 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Storage: Funds deposited towards the returns of a cycle not yet distributed
+    #[pallet::storage]
+    #[pallet::getter(fn return_funds)]
+    pub type ReturnFunds<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Distributed cycles whose returns are being paid out
+    #[pallet::storage]
+    #[pallet::getter(fn pending_payouts)]
+    pub type PendingPayouts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage: Cycle being paid out, the number of its contributors who
+    /// could not be paid so far and the raw key of the last contribution handled
+    #[pallet::storage]
+    pub type PayoutCursor<T: Config> = StorageValue<
+        _,
+        (ClubId, ContributionCycleId, u32, BoundedVec<u8, ConstU32<256>>),
+        OptionQuery,
+    >;
+
+    /// Storage: Returns claimed per cycle and contributor
+    #[pallet::storage]
+    #[pallet::getter(fn claimed_returns)]
+    pub type ClaimedReturns<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ContributionCycleId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Contribution cycle of a club closing at a block
+    #[pallet::storage]
+    pub type CycleClosings<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        ClubId,
+        ContributionCycleId,
+        OptionQuery,
+    >;
+
+    /// Storage: First block whose cycle closings have not all been handled,
+    /// if closings fell behind
+    #[pallet::storage]
+    pub type ClosingCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Storage: Clubs whose cycles are followed by a new cycle with the same
+    /// parameters when they close
+    #[pallet::storage]
+    #[pallet::getter(fn recurring_cycles)]
+    pub type RecurringCycles<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        bool,
+        ValueQuery,
+    >;
+
     /// Storage: Contributions per cycle
     #[pallet::storage]
     #[pallet::getter(fn contributions)]
@@ -187,92 +540,712 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Funds deposited to treasury
-        FundsDeposited {
-            club_id: ClubId,
-            account: T::AccountId,
-            amount: BalanceOf<T>,
-        },
-        /// Withdrawal request created
-        WithdrawalRequested {
-            club_id: ClubId,
-            withdrawal_id: WithdrawalId,
-            recipient: T::AccountId,
-            amount: BalanceOf<T>,
-        },
-        /// Withdrawal approved
-        WithdrawalApproved {
-            club_id: ClubId,
-            withdrawal_id: WithdrawalId,
-            approver: T::AccountId,
-        },
-        /// Withdrawal executed
-        WithdrawalExecuted {
-            club_id: ClubId,
-            withdrawal_id: WithdrawalId,
-            recipient: T::AccountId,
-            amount: BalanceOf<T>,
-        },
-        /// Contribution cycle opened
-        CycleOpened {
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-            end_block: BlockNumberFor<T>,
-        },
-        /// Contribution made to cycle
-        ContributionMade {
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-            contributor: T::AccountId,
-            amount: BalanceOf<T>,
-        },
-        /// Cycle closed
-        CycleClosed {
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-            total_contributions: BalanceOf<T>,
-        },
-        /// Returns distributed
-        ReturnsDistributed {
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-            total_returns: BalanceOf<T>,
-        },
-    }
-
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Insufficient balance
-        InsufficientBalance,
-        /// Withdrawal not found
-        WithdrawalNotFound,
-        /// Withdrawal not pending
-        WithdrawalNotPending,
-        /// Already signed
-        AlreadySigned,
-        /// Insufficient signatures
-        InsufficientSignatures,
-        /// Withdrawal not ready
-        WithdrawalNotReady,
-        /// Cycle not found
-        CycleNotFound,
-        /// Cycle not open
-        CycleNotOpen,
-        /// Contribution below minimum
-        ContributionBelowMinimum,
-        /// Cycle not closed
-        CycleNotClosed,
-        /// No returns to distribute
-        NoReturnsToDistribute,
-    }
+    /// Storage: Separate contributions per cycle and member, in the order made
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_lots)]
+    pub type ContributionLots<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ContributionCycleId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BoundedVec<ContributionLot<T>, T::MaxContributionLots>,
+        ValueQuery,
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Deposit funds to club treasury
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+    /// Storage: Club units held per member
+    #[pallet::storage]
+    #[pallet::getter(fn units)]
+    pub type Units<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Club units in issue
+    #[pallet::storage]
+    #[pallet::getter(fn total_units)]
+    pub type TotalUnits<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, BalanceOf<T>, ValueQuery>;
+
+    /// Storage: Value of the positions a club holds outside its treasury, as
+    /// last set by club governance
+    #[pallet::storage]
+    #[pallet::getter(fn position_value)]
+    pub type PositionValues<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, BalanceOf<T>, ValueQuery>;
+
+    /// Storage: Units taken out of issue for a redemption until the withdrawal
+    /// paying it out is executed or dropped
+    #[pallet::storage]
+    #[pallet::getter(fn redemptions)]
+    pub type Redemptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        WithdrawalId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Share mode applied to new cycles of a club
+    #[pallet::storage]
+    #[pallet::getter(fn share_mode)]
+    pub type ClubShareModes<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, ShareMode, ValueQuery>;
+
+    /// Storage: Share mode of a cycle, fixed when it opened
+    #[pallet::storage]
+    #[pallet::getter(fn cycle_share_mode)]
+    pub type CycleShareModes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        ShareMode,
+        ValueQuery,
+    >;
+
+    /// Storage: Sum of the time-weighted shares of a time-weighted cycle
+    #[pallet::storage]
+    #[pallet::getter(fn cycle_share_weight)]
+    pub type CycleShareWeights<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        u128,
+        ValueQuery,
+    >;
+
+    /// Storage: Payment terms applied to new cycles of a club
+    #[pallet::storage]
+    #[pallet::getter(fn payment_terms)]
+    pub type ClubPaymentTerms<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        PaymentTerms<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Payment terms of a cycle, fixed when it opened
+    #[pallet::storage]
+    #[pallet::getter(fn cycle_payment_terms)]
+    pub type CyclePaymentTerms<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        PaymentTerms<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Number of cycles each member missed contributing to
+    #[pallet::storage]
+    #[pallet::getter(fn missed_payment_count)]
+    pub type MissedPaymentCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Storage: Cycles in which a member's missed contribution was counted
+    #[pallet::storage]
+    pub type ReportedMisses<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ContributionCycleId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage: Contribution mandates per club and member
+    #[pallet::storage]
+    #[pallet::getter(fn mandates)]
+    pub type Mandates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        T::AccountId,
+        ContributionMandate<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Cycles whose mandates are still to be collected
+    #[pallet::storage]
+    #[pallet::getter(fn pending_collections)]
+    pub type PendingCollections<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        ContributionCycleId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage: Cycle whose mandates are being collected and the raw key of
+    /// the last mandate handled
+    #[pallet::storage]
+    pub type CollectionCursor<T: Config> = StorageValue<
+        _,
+        (ClubId, ContributionCycleId, BoundedVec<u8, ConstU32<256>>),
+        OptionQuery,
+    >;
+
+    /// Storage: Contributions owed by members for a cycle but not paid
+    #[pallet::storage]
+    #[pallet::getter(fn missed_contributions)]
+    pub type MissedContributions<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, ContributionCycleId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Rotating savings groups
+    #[pallet::storage]
+    #[pallet::getter(fn roscas)]
+    pub type Roscas<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        RoscaId,
+        Rosca<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Rotating savings group counter per club
+    #[pallet::storage]
+    #[pallet::getter(fn rosca_count)]
+    pub type RoscaCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        RoscaId,
+        ValueQuery,
+    >;
+
+    /// Storage: Block at which a participant paid into a ROSCA round
+    #[pallet::storage]
+    #[pallet::getter(fn round_contributions)]
+    pub type RoundContributions<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, RoscaId>,
+            NMapKey<Blake2_128Concat, RoundIndex>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Round in which a participant received the pot
+    #[pallet::storage]
+    #[pallet::getter(fn rosca_recipients)]
+    pub type RoscaRecipients<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, RoscaId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        RoundIndex,
+        OptionQuery,
+    >;
+
+    /// Storage: Leading bid and discount offered in the current round of a
+    /// bidding ROSCA
+    #[pallet::storage]
+    #[pallet::getter(fn leading_bid)]
+    pub type LeadingBids<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        RoscaId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Storage: Reveal period closing each round of a sealed-bid ROSCA auction
+    #[pallet::storage]
+    #[pallet::getter(fn sealed_auction)]
+    pub type SealedAuctions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClubId,
+        Blake2_128Concat,
+        RoscaId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Sealed bids of the current round, hashes of the SCALE-encoded
+    /// `(bidder, rosca_id, round, discount, salt)` tuple
+    #[pallet::storage]
+    #[pallet::getter(fn bid_commitments)]
+    pub type BidCommitments<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, ClubId>,
+            NMapKey<Blake2_128Concat, RoscaId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        T::Hash,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Funds deposited to treasury
+        FundsDeposited {
+            club_id: ClubId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Withdrawal request created
+        WithdrawalRequested {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Signer set of a club replaced through governance
+        SignerSetUpdated {
+            club_id: ClubId,
+            signers: Vec<T::AccountId>,
+            threshold: u32,
+            version: SignerSetVersion,
+        },
+        /// Signatures of a withdrawal were discarded because the signer set changed
+        WithdrawalSignaturesReset {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+            version: SignerSetVersion,
+        },
+        /// Recorded treasury balance was brought in line with the treasury account
+        TreasuryReconciled {
+            club_id: ClubId,
+            recorded: BalanceOf<T>,
+            actual: BalanceOf<T>,
+        },
+        /// Funds paid out of a treasury by another pallet
+        FundsWithdrawn {
+            club_id: ClubId,
+            dest: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Withdrawal approved
+        WithdrawalApproved {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+            approver: T::AccountId,
+        },
+        /// A signer voted to reject a withdrawal
+        WithdrawalRejectionCast {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+            signer: T::AccountId,
+        },
+        /// Enough signers rejected a withdrawal for it to be dropped
+        WithdrawalRejected {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        },
+        /// The requester cancelled a withdrawal
+        WithdrawalCancelled {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        },
+        /// A withdrawal was not approved in time and was cancelled
+        WithdrawalExpired {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        },
+        /// Spending limits of a club were set
+        SpendingLimitSet {
+            club_id: ClubId,
+            max_per_withdrawal: BalanceOf<T>,
+            period_cap: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+        },
+        /// Spending limits of a club were removed
+        SpendingLimitRemoved {
+            club_id: ClubId,
+        },
+        /// Governance allowed spending beyond the limits
+        SpendingOverrideGranted {
+            club_id: ClubId,
+            amount: BalanceOf<T>,
+        },
+        /// Withdrawal executed
+        WithdrawalExecuted {
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Contribution cycle opened
+        CycleOpened {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            end_block: BlockNumberFor<T>,
+        },
+        /// Club turned automatic opening of its next cycle on or off
+        RecurringCyclesSet {
+            club_id: ClubId,
+            enabled: bool,
+        },
+        /// Share mode for new cycles of a club was set
+        ShareModeSet {
+            club_id: ClubId,
+            mode: ShareMode,
+        },
+        /// Club units were issued against a contribution
+        UnitsMinted {
+            club_id: ClubId,
+            account: T::AccountId,
+            units: BalanceOf<T>,
+            value: BalanceOf<T>,
+        },
+        /// Club units were redeemed for treasury funds
+        UnitsBurned {
+            club_id: ClubId,
+            account: T::AccountId,
+            units: BalanceOf<T>,
+            value: BalanceOf<T>,
+        },
+        /// Redemption of club units was requested and awaits the signers'
+        /// approval of the withdrawal paying it out
+        RedemptionRequested {
+            club_id: ClubId,
+            account: T::AccountId,
+            withdrawal_id: WithdrawalId,
+            units: BalanceOf<T>,
+            value: BalanceOf<T>,
+        },
+        /// Units of a redemption whose withdrawal was dropped were returned
+        RedemptionReleased {
+            club_id: ClubId,
+            account: T::AccountId,
+            withdrawal_id: WithdrawalId,
+            units: BalanceOf<T>,
+        },
+        /// Value of a club's positions outside the treasury was set
+        PositionsValued {
+            club_id: ClubId,
+            value: BalanceOf<T>,
+        },
+        /// The next cycle of a recurring schedule could not be opened
+        CycleRolloverFailed {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            error: DispatchError,
+        },
+        /// Contribution made to cycle
+        ContributionMade {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            contributor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Cycle closed
+        CycleClosed {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            total_contributions: BalanceOf<T>,
+        },
+        /// Returns distributed
+        ReturnsDistributed {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            total_returns: BalanceOf<T>,
+        },
+        /// Funds deposited towards the returns of a cycle
+        ReturnsFunded {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Contributor claimed their share of a cycle's returns
+        ReturnsClaimed {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Unclaimed returns were released back to the treasury
+        UnclaimedReturnsSwept {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            amount: BalanceOf<T>,
+        },
+        /// Returns of a cycle were queued to be paid out to its contributors
+        ReturnsPayoutQueued {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+        },
+        /// Part of a cycle's returns were paid out in this block
+        ReturnsPayoutProgress {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            paid_accounts: u32,
+            amount: BalanceOf<T>,
+        },
+        /// Every contributor of a cycle was paid or left to claim; the rest
+        /// went back to the treasury unless some could not be paid
+        ReturnsPayoutCompleted {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            unpaid_accounts: u32,
+            dust: BalanceOf<T>,
+        },
+        /// Payment terms for new cycles of a club were set
+        PaymentTermsSet {
+            club_id: ClubId,
+            grace_period: BlockNumberFor<T>,
+            late_fee: BalanceOf<T>,
+        },
+        /// Late fee paid with a contribution made in the grace period
+        LateFeePaid {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+            fee: BalanceOf<T>,
+        },
+        /// A member did not contribute the minimum to a cycle
+        MissedContributionReported {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+            owed: BalanceOf<T>,
+            misses: u32,
+        },
+        /// Member authorized the treasury to pull contributions
+        MandateSet {
+            club_id: ClubId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            cap: BalanceOf<T>,
+        },
+        /// Member revoked their contribution mandate
+        MandateRevoked {
+            club_id: ClubId,
+            account: T::AccountId,
+        },
+        /// A mandated contribution could not be collected
+        ContributionMissed {
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Rotating savings group started
+        RoscaOpened {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            contribution: BalanceOf<T>,
+            order: PayoutOrder,
+        },
+        /// Participant paid into a ROSCA round
+        RoundContributionMade {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            contributor: T::AccountId,
+        },
+        /// Participant did not pay into a ROSCA round
+        RoundContributionMissed {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            participant: T::AccountId,
+        },
+        /// New leading bid in a bidding ROSCA round
+        RoundBidPlaced {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            bidder: T::AccountId,
+            discount: BalanceOf<T>,
+        },
+        /// Pot of a ROSCA round paid to its recipient
+        RoundPaidOut {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+            discount: BalanceOf<T>,
+        },
+        /// Sealed bid committed in a ROSCA auction round
+        RoundBidCommitted {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            bidder: T::AccountId,
+        },
+        /// Discount of an auction round shared among the other contributors
+        AuctionDividendsPaid {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            per_participant: BalanceOf<T>,
+            participants: u32,
+        },
+        /// No eligible recipient; the pot carries over to the next round
+        RoundRolledOver {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            round: RoundIndex,
+            pot: BalanceOf<T>,
+        },
+        /// Every participant of a ROSCA received a pot
+        RoscaCompleted {
+            club_id: ClubId,
+            rosca_id: RoscaId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Insufficient balance
+        InsufficientBalance,
+        /// Withdrawal not found
+        WithdrawalNotFound,
+        /// Withdrawal not pending
+        WithdrawalNotPending,
+        /// Already signed
+        AlreadySigned,
+        /// Insufficient signatures
+        InsufficientSignatures,
+        /// Withdrawal not ready
+        WithdrawalNotReady,
+        /// Cycle not found
+        CycleNotFound,
+        /// Cycle not open
+        CycleNotOpen,
+        /// Contribution below minimum
+        ContributionBelowMinimum,
+        /// Cycle not closed
+        CycleNotClosed,
+        /// No returns to distribute
+        NoReturnsToDistribute,
+        /// Club has no signer set
+        NoSignerSet,
+        /// Account is not a signer of the club
+        NotSigner,
+        /// Signer set is empty, has duplicates or an unreachable threshold
+        InvalidSignerSet,
+        /// Signer set changed since the withdrawal was approved
+        SignerSetChanged,
+        /// Only the requester can cancel a withdrawal
+        NotRequester,
+        /// Withdrawal was already executed, cancelled or rejected
+        WithdrawalClosed,
+        /// Too many withdrawal requests expire in the same block
+        TooManyExpiringWithdrawals,
+        /// Spending would exceed the club's spending limits
+        SpendingLimitExceeded,
+        /// Spending period is zero or the per-withdrawal maximum exceeds the cap
+        InvalidSpendingLimit,
+        /// ROSCA not found
+        RoscaNotFound,
+        /// ROSCA needs two distinct participants, a contribution of at least
+        /// the minimum and a non-zero round length
+        InvalidRosca,
+        /// ROSCA already completed
+        RoscaNotActive,
+        /// Account does not take part in the ROSCA
+        NotParticipant,
+        /// Account is not a member of the club or is suspended
+        NotClubMember,
+        /// Participant already paid into this round
+        AlreadyContributed,
+        /// The current round is over and awaits settlement
+        RoundOver,
+        /// The current round is still running
+        RoundNotOver,
+        /// No randomness revealed after the round ended is available yet
+        DrawNotReady,
+        /// Participant already received a pot
+        AlreadyReceived,
+        /// ROSCA does not use bidding
+        NotBiddingRosca,
+        /// Bid does not beat the leading bid or exceeds the round's pot
+        InvalidBid,
+        /// Bids of this auction are sealed and have to be committed and revealed
+        SealedAuction,
+        /// Bids of this auction are open
+        OpenAuction,
+        /// Sealed bids are only accepted before the reveal period
+        BiddingClosed,
+        /// Sealed bids can only be revealed during the reveal period
+        NotRevealPeriod,
+        /// Bidder already committed a sealed bid this round
+        AlreadyBid,
+        /// No sealed bid committed by the account
+        BidNotFound,
+        /// Revealed discount and salt do not match the commitment
+        InvalidReveal,
+        /// Mandate amount is zero or exceeds its cap
+        InvalidMandate,
+        /// Account has no contribution mandate
+        MandateNotFound,
+        /// The cycle's grace period has not ended yet
+        GracePeriodNotOver,
+        /// Account was not a member when the cycle opened
+        NotObligated,
+        /// Account contributed at least the minimum to the cycle
+        ContributionNotMissed,
+        /// Missed contribution was already reported
+        AlreadyReported,
+        /// Returns of the cycle were already claimed by the account
+        AlreadyClaimed,
+        /// Claim deadline of the cycle has passed
+        ClaimPeriodOver,
+        /// Claim deadline of the cycle has not passed yet
+        ClaimPeriodNotOver,
+        /// Returns of the cycle are already queued for payout
+        PayoutAlreadyQueued,
+        /// Member made the maximum number of contributions to the cycle
+        TooManyContributionLots,
+        /// Account holds fewer units than needed
+        InsufficientUnits,
+        /// Nothing would be redeemed
+        NothingToRedeem,
+        /// Funds available in the treasury do not cover the redemption
+        InsufficientLiquidity,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Deposit funds to club treasury
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
         #[pallet::call_index(0)]
         pub fn deposit(
             origin: OriginFor<T>,
@@ -281,33 +1254,1693 @@ pub mod pallet {
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
             
-            T::Currency::transfer(&account, &Self::treasury_account_id(club_id), amount, ExistenceRequirement::KeepAlive)?;
+            T::Currency::transfer(&account, &Self::treasury_account_id(club_id), amount, ExistenceRequirement::KeepAlive)?;
+            
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(amount));
+            
+            Self::deposit_event(Event::FundsDeposited {
+                club_id,
+                account,
+                amount,
+            });
+            
+            Ok(())
+        }
+
+        /// Create a withdrawal request
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(1)]
+        pub fn request_withdrawal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+            unlock_delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            
+            let signer_set = Self::signer_set(club_id).ok_or(Error::<T>::NoSignerSet)?;
+            ensure!(signer_set.signers.contains(&requester), Error::<T>::NotSigner);
+            
+            let mut signatures = BoundedVec::new();
+            signatures.try_push(requester.clone())
+                .map_err(|_| Error::<T>::InsufficientSignatures)?;
+            
+            Self::do_request_withdrawal(club_id, &signer_set, requester, recipient, amount, unlock_delay, signatures)?;
+            
+            Ok(())
+        }
+
+        /// Approve a withdrawal request
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(2)]
+        pub fn approve_withdrawal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        ) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            
+            let signer_set = Self::signer_set(club_id).ok_or(Error::<T>::NoSignerSet)?;
+            ensure!(signer_set.signers.contains(&approver), Error::<T>::NotSigner);
+            
+            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
+                .ok_or(Error::<T>::WithdrawalNotFound)?;
+            
+            Self::refresh_signatures(&mut withdrawal, &signer_set);
+            
+            ensure!(
+                withdrawal.status == WithdrawalStatus::Pending,
+                Error::<T>::WithdrawalNotPending
+            );
+            
+            ensure!(
+                !withdrawal.signatures.contains(&approver)
+                    && !withdrawal.rejections.contains(&approver),
+                Error::<T>::AlreadySigned
+            );
+            
+            withdrawal.signatures.try_push(approver.clone())
+                .map_err(|_| Error::<T>::InsufficientSignatures)?;
+            
+            // Check if we have enough signatures
+            if withdrawal.signatures.len() >= signer_set.threshold as usize {
+                withdrawal.status = WithdrawalStatus::Approved;
+            }
+            
+            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            
+            Self::deposit_event(Event::WithdrawalApproved {
+                club_id,
+                withdrawal_id,
+                approver,
+            });
+            
+            Ok(())
+        }
+
+        /// Execute an approved withdrawal
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(3)]
+        pub fn execute_withdrawal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
+                .ok_or(Error::<T>::WithdrawalNotFound)?;
+            
+            ensure!(
+                withdrawal.status == WithdrawalStatus::Approved,
+                Error::<T>::WithdrawalNotPending
+            );
+            
+            let signer_set = Self::signer_set(club_id).ok_or(Error::<T>::NoSignerSet)?;
+            ensure!(
+                withdrawal.signer_set_version == signer_set.version,
+                Error::<T>::SignerSetChanged
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now >= withdrawal.unlock_at,
+                Error::<T>::WithdrawalNotReady
+            );
+            
+            // The amount was earmarked when the request was made
+            let balance = Self::treasury_balance(club_id);
+            ensure!(balance >= withdrawal.amount, Error::<T>::InsufficientBalance);
+            
+            Self::charge_spending(club_id, withdrawal.amount)?;
+            
+            // Transfer funds
+            T::Currency::transfer(
+                &Self::treasury_account_id(club_id),
+                &withdrawal.recipient,
+                withdrawal.amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_sub(withdrawal.amount));
+            Self::release_earmark(club_id, withdrawal.amount);
+            
+            withdrawal.status = WithdrawalStatus::Executed;
+            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            Self::complete_redemption(&withdrawal);
+            
+            Self::deposit_event(Event::WithdrawalExecuted {
+                club_id,
+                withdrawal_id,
+                recipient: withdrawal.recipient,
+                amount: withdrawal.amount,
+            });
+            
+            Ok(())
+        }
+
+        /// Open a new contribution cycle
+        ///
+        /// Only members in good standing open cycles for their club.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(7, 7))]
+        #[pallet::call_index(4)]
+        pub fn open_contribution_cycle(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            contribution_period: Option<BlockNumberFor<T>>,
+            minimum_contribution: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_member(club_id, &who), Error::<T>::NotClubMember);
+            
+            let period = contribution_period.unwrap_or(T::DefaultContributionPeriod::get());
+            let min_contrib = minimum_contribution.unwrap_or(T::MinContribution::get());
+            Self::do_open_cycle(club_id, period, min_contrib)?;
+            
+            Ok(())
+        }
+
+        /// Contribute to the active cycle
+        ///
+        /// Only members in good standing contribute.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(7, 6))]
+        #[pallet::call_index(5)]
+        pub fn contribute(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_member(club_id, &contributor), Error::<T>::NotClubMember);
+            
+            let cycle_id = Self::active_cycle(club_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Open,
+                Error::<T>::CycleNotOpen
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now <= Self::due_by(&cycle),
+                Error::<T>::CycleNotOpen
+            );
+            
+            Self::record_contribution(&mut cycle, &contributor, amount)?;
+            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            
+            // Contributions in the grace period pay a late fee
+            let terms = Self::cycle_payment_terms(club_id, cycle_id)
+                .filter(|terms| now > cycle.end_block && !terms.late_fee.is_zero());
+            if let Some(terms) = terms {
+                T::Currency::transfer(
+                    &contributor,
+                    &Self::treasury_account_id(club_id),
+                    terms.late_fee,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+                TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(terms.late_fee));
+                
+                Self::deposit_event(Event::LateFeePaid {
+                    club_id,
+                    cycle_id,
+                    account: contributor,
+                    fee: terms.late_fee,
+                });
+            }
+            
+            Ok(())
+        }
+
+        /// Close the active contribution cycle
+        ///
+        /// Cycles also close on their own in the block after their grace period.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6, 8))]
+        #[pallet::call_index(6)]
+        pub fn close_cycle(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let cycle_id = Self::active_cycle(club_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            let cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Open,
+                Error::<T>::CycleNotOpen
+            );
+            
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() > Self::due_by(&cycle),
+                Error::<T>::CycleNotClosed
+            );
+            
+            Self::do_close_cycle(cycle);
+            
+            Ok(())
+        }
+
+        /// Set returns for a closed cycle of the calling club and hold them
+        /// for its contributors until the claim deadline
+        ///
+        /// Returns are paid first from funds deposited for the cycle, then
+        /// from treasury funds not allocated elsewhere. Deposited funds beyond
+        /// `returns` become free treasury funds.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4, 4))]
+        #[pallet::call_index(7)]
+        pub fn distribute_returns(
+            origin: OriginFor<T>,
+            cycle_id: ContributionCycleId,
+            returns: BalanceOf<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Closed,
+                Error::<T>::CycleNotClosed
+            );
+            
+            ensure!(
+                returns > Zero::zero(),
+                Error::<T>::NoReturnsToDistribute
+            );
+            
+            // Deposited funds are already earmarked for the cycle
+            let funded = ReturnFunds::<T>::take(club_id, cycle_id);
+            if returns > funded {
+                let unfunded = returns.saturating_sub(funded);
+                ensure!(
+                    Self::available_balance(club_id) >= unfunded,
+                    Error::<T>::InsufficientBalance
+                );
+                Earmarked::<T>::mutate(club_id, |earmarked| *earmarked = earmarked.saturating_add(unfunded));
+            } else {
+                Self::release_earmark(club_id, funded.saturating_sub(returns));
+            }
+            
+            // Returns are held for contributors, who claim their share
+            // proportionally: share = (user_contribution / total_contribution) * returns
+            let now = <frame_system::Pallet<T>>::block_number();
+            cycle.returns = returns;
+            cycle.status = CycleStatus::Distributed;
+            cycle.claim_deadline = Some(now.saturating_add(T::ClaimPeriod::get()));
+            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            
+            Self::deposit_event(Event::ReturnsDistributed {
+                club_id,
+                cycle_id,
+                total_returns: returns,
+            });
+            
+            Ok(())
+        }
+
+        /// Claim returns from a distributed cycle
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(5))]
+        #[pallet::call_index(8)]
+        pub fn claim_returns(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+        ) -> DispatchResult {
+            let claimant = ensure_signed(origin)?;
+            
+            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Distributed,
+                Error::<T>::CycleNotClosed
+            );
+            ensure!(
+                cycle.claim_deadline.map_or(true, |deadline| <frame_system::Pallet<T>>::block_number() <= deadline),
+                Error::<T>::ClaimPeriodOver
+            );
+            ensure!(
+                !ClaimedReturns::<T>::contains_key((club_id, cycle_id, &claimant)),
+                Error::<T>::AlreadyClaimed
+            );
+            
+            let contribution = Self::contributions((club_id, cycle_id, &claimant))
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            let share = Self::return_share(&cycle, &claimant, contribution.amount);
+            ensure!(
+                share > Zero::zero(),
+                Error::<T>::NoReturnsToDistribute
+            );
+            
+            Self::pay_return_share(&mut cycle, &claimant, share)?;
+            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            
+            Ok(())
+        }
+
+        /// Replace the withdrawal signer set of the club whose governance
+        /// dispatches this call
+        ///
+        /// Signatures on requests not yet executed are invalidated and have
+        /// to be collected again from the new signers.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(9)]
+        pub fn set_signers(
+            origin: OriginFor<T>,
+            signers: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            ensure!(
+                threshold >= T::MinSignatures::get().max(1)
+                    && threshold as usize <= signers.len()
+                    && signers.iter().enumerate().all(|(i, signer)| !signers[..i].contains(signer)),
+                Error::<T>::InvalidSignerSet
+            );
+            
+            let version = Self::signer_set(club_id)
+                .map_or(1, |set| set.version.saturating_add(1));
+            let signer_set = SignerSet {
+                signers: BoundedVec::try_from(signers.clone())
+                    .map_err(|_| Error::<T>::InvalidSignerSet)?,
+                threshold,
+                version,
+            };
+            ClubSigners::<T>::insert(club_id, signer_set);
+            
+            Self::deposit_event(Event::SignerSetUpdated {
+                club_id,
+                signers,
+                threshold,
+                version,
+            });
+            
+            Ok(())
+        }
+
+        /// Bring a club's recorded treasury balance in line with the free
+        /// balance of its treasury account
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+        #[pallet::call_index(10)]
+        pub fn reconcile_treasury(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            Self::reconcile(club_id);
+            
+            Ok(())
+        }
+
+        /// Vote to reject a withdrawal request
+        ///
+        /// The request is dropped once so many signers reject it that the
+        /// approval threshold can no longer be reached.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(11)]
+        pub fn reject_withdrawal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            
+            let signer_set = Self::signer_set(club_id).ok_or(Error::<T>::NoSignerSet)?;
+            ensure!(signer_set.signers.contains(&signer), Error::<T>::NotSigner);
+            
+            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
+                .ok_or(Error::<T>::WithdrawalNotFound)?;
+            
+            Self::refresh_signatures(&mut withdrawal, &signer_set);
+            
+            ensure!(
+                matches!(withdrawal.status, WithdrawalStatus::Pending | WithdrawalStatus::Approved),
+                Error::<T>::WithdrawalClosed
+            );
+            ensure!(
+                !withdrawal.signatures.contains(&signer)
+                    && !withdrawal.rejections.contains(&signer),
+                Error::<T>::AlreadySigned
+            );
+            
+            withdrawal.rejections.try_push(signer.clone())
+                .map_err(|_| Error::<T>::InsufficientSignatures)?;
+            
+            Self::deposit_event(Event::WithdrawalRejectionCast {
+                club_id,
+                withdrawal_id,
+                signer,
+            });
+            
+            let rejection_threshold = signer_set.signers.len()
+                .saturating_sub(signer_set.threshold as usize)
+                .saturating_add(1);
+            if withdrawal.rejections.len() >= rejection_threshold {
+                withdrawal.status = WithdrawalStatus::Rejected;
+                Self::release_earmark(club_id, withdrawal.amount);
+                Self::release_redemption(&withdrawal);
+                Self::deposit_event(Event::WithdrawalRejected {
+                    club_id,
+                    withdrawal_id,
+                });
+            }
+            
+            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            
+            Ok(())
+        }
+
+        /// Cancel a withdrawal request that has not been executed
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(12)]
+        pub fn cancel_withdrawal(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            withdrawal_id: WithdrawalId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            
+            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
+                .ok_or(Error::<T>::WithdrawalNotFound)?;
+            
+            ensure!(withdrawal.requester == who, Error::<T>::NotRequester);
+            ensure!(
+                matches!(withdrawal.status, WithdrawalStatus::Pending | WithdrawalStatus::Approved),
+                Error::<T>::WithdrawalClosed
+            );
+            
+            withdrawal.status = WithdrawalStatus::Cancelled;
+            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            Self::release_earmark(club_id, withdrawal.amount);
+            Self::release_redemption(&withdrawal);
+            
+            Self::deposit_event(Event::WithdrawalCancelled {
+                club_id,
+                withdrawal_id,
+            });
+            
+            Ok(())
+        }
+
+        /// Set the spending limits of the calling club
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(13)]
+        pub fn set_spending_limit(
+            origin: OriginFor<T>,
+            max_per_withdrawal: BalanceOf<T>,
+            period_cap: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            ensure!(
+                !period.is_zero() && max_per_withdrawal <= period_cap,
+                Error::<T>::InvalidSpendingLimit
+            );
+            
+            SpendingLimits::<T>::insert(club_id, SpendingLimit {
+                max_per_withdrawal,
+                period_cap,
+                period,
+            });
+            
+            Self::deposit_event(Event::SpendingLimitSet {
+                club_id,
+                max_per_withdrawal,
+                period_cap,
+                period,
+            });
+            
+            Ok(())
+        }
+
+        /// Remove the spending limits of the calling club
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        #[pallet::call_index(14)]
+        pub fn remove_spending_limit(
+            origin: OriginFor<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            SpendingLimits::<T>::remove(club_id);
+            SpendingWindows::<T>::remove(club_id);
+            
+            Self::deposit_event(Event::SpendingLimitRemoved { club_id });
+            
+            Ok(())
+        }
+
+        /// Allow the calling club to spend `amount` beyond its spending limits
+        ///
+        /// The allowance is used up by spends that would otherwise exceed a
+        /// limit, and replaces any allowance granted earlier.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(15)]
+        pub fn grant_spending_override(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            SpendingOverrides::<T>::insert(club_id, amount);
+            
+            Self::deposit_event(Event::SpendingOverrideGranted {
+                club_id,
+                amount,
+            });
+            
+            Ok(())
+        }
+
+        /// Start a rotating savings group for the calling club
+        ///
+        /// For a fixed payout order, participants receive in the order given.
+        /// All participants must be members of the club.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            T::MaxRoscaParticipants::get() as u64,
+            2,
+        ))]
+        #[pallet::call_index(16)]
+        pub fn open_rosca(
+            origin: OriginFor<T>,
+            participants: Vec<T::AccountId>,
+            contribution: BalanceOf<T>,
+            round_length: BlockNumberFor<T>,
+            order: PayoutOrder,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            Self::do_open_rosca(club_id, participants, contribution, round_length, order)?;
+            
+            Ok(())
+        }
+
+        /// Pay the fixed contribution into the current round of a ROSCA
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(4))]
+        #[pallet::call_index(17)]
+        pub fn contribute_to_round(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            rosca_id: RoscaId,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+            
+            let mut rosca = Self::roscas(club_id, rosca_id)
+                .ok_or(Error::<T>::RoscaNotFound)?;
+            
+            ensure!(rosca.status == RoscaStatus::Active, Error::<T>::RoscaNotActive);
+            ensure!(rosca.participants.contains(&contributor), Error::<T>::NotParticipant);
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now < rosca.round_start.saturating_add(rosca.round_length),
+                Error::<T>::RoundOver
+            );
+            ensure!(
+                !RoundContributions::<T>::contains_key((club_id, rosca_id, rosca.round, &contributor)),
+                Error::<T>::AlreadyContributed
+            );
+            
+            T::Currency::transfer(
+                &contributor,
+                &Self::treasury_account_id(club_id),
+                rosca.contribution,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            
+            // The pot belongs to the round's recipient, keep it out of reach of withdrawals
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(rosca.contribution));
+            Earmarked::<T>::mutate(club_id, |earmarked| *earmarked = earmarked.saturating_add(rosca.contribution));
+            RoundContributions::<T>::insert((club_id, rosca_id, rosca.round, &contributor), now);
+            rosca.pot = rosca.pot.saturating_add(rosca.contribution);
+            Roscas::<T>::insert(club_id, rosca_id, &rosca);
+            
+            Self::deposit_event(Event::RoundContributionMade {
+                club_id,
+                rosca_id,
+                round: rosca.round,
+                contributor,
+            });
+            
+            Ok(())
+        }
+
+        /// Bid for the pot of the current round of a bidding ROSCA
+        ///
+        /// `discount` is the part of the pot the bidder gives up; the largest
+        /// discount wins.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(18)]
+        pub fn bid_for_round(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            discount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            
+            let rosca = Self::roscas(club_id, rosca_id)
+                .ok_or(Error::<T>::RoscaNotFound)?;
+            
+            ensure!(rosca.status == RoscaStatus::Active, Error::<T>::RoscaNotActive);
+            ensure!(rosca.order == PayoutOrder::Bidding, Error::<T>::NotBiddingRosca);
+            ensure!(!SealedAuctions::<T>::contains_key(club_id, rosca_id), Error::<T>::SealedAuction);
+            ensure!(rosca.participants.contains(&bidder), Error::<T>::NotParticipant);
+            ensure!(
+                !RoscaRecipients::<T>::contains_key((club_id, rosca_id, &bidder)),
+                Error::<T>::AlreadyReceived
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now < rosca.round_start.saturating_add(rosca.round_length),
+                Error::<T>::RoundOver
+            );
+            
+            Self::place_bid(&rosca, bidder, discount)
+        }
+
+        /// Pay out the pot of a ROSCA round that has ended and start the next
+        ///
+        /// Participants who did not pay into the round are skipped as its
+        /// recipient. Without any eligible recipient the pot carries over.
+        /// Lottery rounds are drawn once randomness revealed after the round's
+        /// last block is available. Each participant costs the lookups of its contribution and payout,
+        /// the removal of its sealed bid and a dividend transfer.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            3 * T::MaxRoscaParticipants::get() as u64 + 6,
+            3 * T::MaxRoscaParticipants::get() as u64 + 7,
+        ))]
+        #[pallet::call_index(19)]
+        pub fn settle_round(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            rosca_id: RoscaId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let mut rosca = Self::roscas(club_id, rosca_id)
+                .ok_or(Error::<T>::RoscaNotFound)?;
+            
+            ensure!(rosca.status == RoscaStatus::Active, Error::<T>::RoscaNotActive);
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now >= rosca.round_start.saturating_add(rosca.round_length),
+                Error::<T>::RoundNotOver
+            );
+            
+            let round = rosca.round;
+            let mut contributors = Vec::new();
+            let mut eligible = Vec::new();
+            for participant in rosca.participants.iter() {
+                BidCommitments::<T>::remove((club_id, rosca_id, participant));
+                if !RoundContributions::<T>::contains_key((club_id, rosca_id, round, participant)) {
+                    Self::deposit_event(Event::RoundContributionMissed {
+                        club_id,
+                        rosca_id,
+                        round,
+                        participant: participant.clone(),
+                    });
+                    continue;
+                }
+                contributors.push(participant.clone());
+                if !RoscaRecipients::<T>::contains_key((club_id, rosca_id, participant)) {
+                    eligible.push(participant.clone());
+                }
+            }
+            
+            let leading_bid = LeadingBids::<T>::take(club_id, rosca_id);
+            if let Some((recipient, discount)) = Self::round_recipient(&rosca, &eligible, leading_bid)? {
+                let discount = discount.min(rosca.pot);
+                let amount = rosca.pot.saturating_sub(discount);
+                T::Currency::transfer(
+                    &Self::treasury_account_id(club_id),
+                    &recipient,
+                    amount,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+                
+                TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_sub(amount));
+                Self::release_earmark(club_id, rosca.pot);
+                RoscaRecipients::<T>::insert((club_id, rosca_id, &recipient), round);
+                
+                let others: Vec<T::AccountId> = contributors.into_iter()
+                    .filter(|who| *who != recipient)
+                    .collect();
+                Self::pay_dividends(&rosca, &others, discount)?;
+                rosca.pot = Zero::zero();
+                rosca.paid_out = rosca.paid_out.saturating_add(1);
+                
+                Self::deposit_event(Event::RoundPaidOut {
+                    club_id,
+                    rosca_id,
+                    round,
+                    recipient,
+                    amount,
+                    discount,
+                });
+            } else {
+                Self::deposit_event(Event::RoundRolledOver {
+                    club_id,
+                    rosca_id,
+                    round,
+                    pot: rosca.pot,
+                });
+            }
+            
+            if rosca.paid_out as usize >= rosca.participants.len() {
+                rosca.status = RoscaStatus::Completed;
+                Self::deposit_event(Event::RoscaCompleted { club_id, rosca_id });
+            } else {
+                rosca.round = round.saturating_add(1);
+                rosca.round_start = now;
+            }
+            Roscas::<T>::insert(club_id, rosca_id, &rosca);
+            
+            Ok(())
+        }
+
+        /// Start a ROSCA whose round pots are auctioned for the calling club
+        ///
+        /// With a `reveal_period`, bids are sealed: they are committed during
+        /// the round and revealed in its last `reveal_period` blocks.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+            T::MaxRoscaParticipants::get() as u64,
+            3,
+        ))]
+        #[pallet::call_index(20)]
+        pub fn open_auction_rosca(
+            origin: OriginFor<T>,
+            participants: Vec<T::AccountId>,
+            contribution: BalanceOf<T>,
+            round_length: BlockNumberFor<T>,
+            reveal_period: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            ensure!(
+                reveal_period.map_or(true, |period| !period.is_zero() && period < round_length),
+                Error::<T>::InvalidRosca
+            );
+            
+            let rosca_id = Self::do_open_rosca(club_id, participants, contribution, round_length, PayoutOrder::Bidding)?;
+            if let Some(period) = reveal_period {
+                SealedAuctions::<T>::insert(club_id, rosca_id, period);
+            }
+            
+            Ok(())
+        }
+
+        /// Commit a sealed bid for the pot of the current auction round
+        ///
+        /// `commitment` is the hash of the SCALE-encoded
+        /// `(bidder, rosca_id, round, discount, salt)` tuple, so a revealed bid
+        /// cannot be replayed by another participant or in a later round.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(21)]
+        pub fn commit_bid(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            
+            let rosca = Self::roscas(club_id, rosca_id)
+                .ok_or(Error::<T>::RoscaNotFound)?;
+            let reveal_period = Self::sealed_auction(club_id, rosca_id)
+                .ok_or(Error::<T>::OpenAuction)?;
+            
+            ensure!(rosca.status == RoscaStatus::Active, Error::<T>::RoscaNotActive);
+            ensure!(rosca.participants.contains(&bidder), Error::<T>::NotParticipant);
+            ensure!(
+                !RoscaRecipients::<T>::contains_key((club_id, rosca_id, &bidder)),
+                Error::<T>::AlreadyReceived
+            );
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            let reveal_start = rosca.round_start
+                .saturating_add(rosca.round_length)
+                .saturating_sub(reveal_period);
+            ensure!(now < reveal_start, Error::<T>::BiddingClosed);
+            ensure!(
+                !BidCommitments::<T>::contains_key((club_id, rosca_id, &bidder)),
+                Error::<T>::AlreadyBid
+            );
+            
+            BidCommitments::<T>::insert((club_id, rosca_id, &bidder), commitment);
+            
+            Self::deposit_event(Event::RoundBidCommitted {
+                club_id,
+                rosca_id,
+                round: rosca.round,
+                bidder,
+            });
+            
+            Ok(())
+        }
+
+        /// Reveal a sealed bid during the reveal period of the round
+        ///
+        /// On equal discounts the bid revealed first wins.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        #[pallet::call_index(22)]
+        pub fn reveal_bid(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            rosca_id: RoscaId,
+            discount: BalanceOf<T>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            
+            let rosca = Self::roscas(club_id, rosca_id)
+                .ok_or(Error::<T>::RoscaNotFound)?;
+            let reveal_period = Self::sealed_auction(club_id, rosca_id)
+                .ok_or(Error::<T>::OpenAuction)?;
+            
+            ensure!(rosca.status == RoscaStatus::Active, Error::<T>::RoscaNotActive);
+            
+            let now = <frame_system::Pallet<T>>::block_number();
+            let round_end = rosca.round_start.saturating_add(rosca.round_length);
+            ensure!(
+                now >= round_end.saturating_sub(reveal_period) && now < round_end,
+                Error::<T>::NotRevealPeriod
+            );
+            
+            let commitment = BidCommitments::<T>::take((club_id, rosca_id, &bidder))
+                .ok_or(Error::<T>::BidNotFound)?;
+            ensure!(
+                T::Hashing::hash_of(&(&bidder, rosca_id, rosca.round, discount, salt)) == commitment,
+                Error::<T>::InvalidReveal
+            );
+            
+            Self::place_bid(&rosca, bidder, discount)
+        }
+
+        /// Set the grace period and late fee for new cycles of the calling club
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(25)]
+        pub fn set_payment_terms(
+            origin: OriginFor<T>,
+            grace_period: BlockNumberFor<T>,
+            late_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            ClubPaymentTerms::<T>::insert(club_id, PaymentTerms {
+                grace_period,
+                late_fee,
+            });
+            
+            Self::deposit_event(Event::PaymentTermsSet {
+                club_id,
+                grace_period,
+                late_fee,
+            });
+            
+            Ok(())
+        }
+
+        /// Deposit funds into the treasury towards the returns of a cycle
+        ///
+        /// The funds are held for the cycle until governance distributes its
+        /// returns.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 4))]
+        #[pallet::call_index(29)]
+        pub fn fund_returns(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            
+            let cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                matches!(cycle.status, CycleStatus::Open | CycleStatus::Closed),
+                Error::<T>::CycleNotClosed
+            );
+            ensure!(!amount.is_zero(), Error::<T>::NoReturnsToDistribute);
+            
+            T::Currency::transfer(
+                &account,
+                &Self::treasury_account_id(club_id),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(amount));
+            Earmarked::<T>::mutate(club_id, |earmarked| *earmarked = earmarked.saturating_add(amount));
+            ReturnFunds::<T>::mutate(club_id, cycle_id, |funds| *funds = funds.saturating_add(amount));
+            
+            Self::deposit_event(Event::ReturnsFunded {
+                club_id,
+                cycle_id,
+                account,
+                amount,
+            });
+            
+            Ok(())
+        }
+
+        /// Release returns nobody claimed before the claim deadline back to
+        /// the treasury
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        #[pallet::call_index(28)]
+        pub fn sweep_unclaimed_returns(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Distributed,
+                Error::<T>::CycleNotClosed
+            );
+            ensure!(
+                cycle.claim_deadline.map_or(false, |deadline| <frame_system::Pallet<T>>::block_number() > deadline),
+                Error::<T>::ClaimPeriodNotOver
+            );
+            
+            let unclaimed = cycle.returns.saturating_sub(cycle.claimed_returns);
+            Self::release_earmark(club_id, unclaimed);
+            cycle.status = CycleStatus::Swept;
+            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            
+            Self::deposit_event(Event::UnclaimedReturnsSwept {
+                club_id,
+                cycle_id,
+                amount: unclaimed,
+            });
+            
+            Ok(())
+        }
+
+        /// Pay the returns of a distributed cycle of the calling club to its
+        /// contributors over the next blocks
+        ///
+        /// Contributors who already claimed are skipped. Once everyone has been
+        /// paid, rounding dust is released back to the treasury and the cycle
+        /// can no longer be claimed from. Shares that could not be paid stay
+        /// claimable until the claim deadline.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+        #[pallet::call_index(30)]
+        pub fn push_returns(
+            origin: OriginFor<T>,
+            cycle_id: ContributionCycleId,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            let cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                cycle.status == CycleStatus::Distributed,
+                Error::<T>::CycleNotClosed
+            );
+            
+            ensure!(
+                !PendingPayouts::<T>::contains_key(club_id, cycle_id),
+                Error::<T>::PayoutAlreadyQueued
+            );
+            PendingPayouts::<T>::insert(club_id, cycle_id, ());
+            
+            Self::deposit_event(Event::ReturnsPayoutQueued {
+                club_id,
+                cycle_id,
+            });
+            
+            Ok(())
+        }
+
+        /// Have the calling club's cycles followed by a new cycle with the same
+        /// period and minimum contribution when they close
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(27)]
+        pub fn set_recurring_cycles(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            RecurringCycles::<T>::insert(club_id, enabled);
+            
+            Self::deposit_event(Event::RecurringCyclesSet {
+                club_id,
+                enabled,
+            });
+            
+            Ok(())
+        }
+
+        /// Set how the returns of new cycles of the calling club are shared
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(31)]
+        pub fn set_share_mode(
+            origin: OriginFor<T>,
+            mode: ShareMode,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            ClubShareModes::<T>::insert(club_id, mode);
+            
+            Self::deposit_event(Event::ShareModeSet {
+                club_id,
+                mode,
+            });
+            
+            Ok(())
+        }
+
+        /// Set the value of the positions the calling club holds outside its
+        /// treasury, used in its net asset value
+        ///
+        /// The value prices new units but not redemptions, which are paid
+        /// from treasury funds alone.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        #[pallet::call_index(32)]
+        pub fn set_position_value(
+            origin: OriginFor<T>,
+            value: BalanceOf<T>,
+        ) -> DispatchResult {
+            let club_id = T::GovernanceOrigin::ensure_origin(origin)?;
+            
+            PositionValues::<T>::insert(club_id, value);
+            
+            Self::deposit_event(Event::PositionsValued {
+                club_id,
+                value,
+            });
+            
+            Ok(())
+        }
+
+        /// Request redemption of `units` of the caller's club units at the
+        /// club's net asset value
+        ///
+        /// The units are taken out of issue and their value is paid by a
+        /// withdrawal request that the club's signers approve like any other.
+        /// Positions held elsewhere are not sold to pay redemptions, so a
+        /// redemption worth more than the funds available in the treasury fails.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(9, 7))]
+        #[pallet::call_index(33)]
+        pub fn redeem_units(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            units: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            
+            let value = Self::units_value(club_id, units);
+            ensure!(
+                !value.is_zero(),
+                Error::<T>::NothingToRedeem
+            );
+            ensure!(
+                value <= Self::available_balance(club_id),
+                Error::<T>::InsufficientLiquidity
+            );
+            
+            Self::request_redemption(club_id, account, units, value)
+        }
+
+        /// Request a withdrawal of `amount` of the caller's equity from the
+        /// club treasury, redeeming the units it is worth
+        ///
+        /// Units are priced at the club's net asset value and the withdrawal
+        /// is paid from the funds available in the treasury, as for
+        /// [`Pallet::redeem_units`].
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(9, 7))]
+        #[pallet::call_index(34)]
+        pub fn withdraw_equity(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            
+            ensure!(
+                !amount.is_zero(),
+                Error::<T>::NothingToRedeem
+            );
+            ensure!(
+                amount <= Self::available_balance(club_id),
+                Error::<T>::InsufficientLiquidity
+            );
             
-            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(amount));
+            // Round the units up so withdrawals never take more than their share
+            let units = multiply_by_rational_with_rounding(
+                amount.saturated_into(),
+                Self::total_units(club_id).saturated_into(),
+                Self::net_asset_value(club_id).saturated_into(),
+                Rounding::Up,
+            )
+            .map(BalanceOf::<T>::saturated_from)
+            .unwrap_or_else(Zero::zero);
+            ensure!(
+                !units.is_zero(),
+                Error::<T>::InsufficientUnits
+            );
             
-            Self::deposit_event(Event::FundsDeposited {
+            Self::request_redemption(club_id, account, units, amount)
+        }
+
+        /// Record that a member did not contribute the minimum to a cycle
+        /// whose grace period is over
+        ///
+        /// Only members who had joined when the cycle opened are obliged to
+        /// contribute. A miss already recorded for an uncollected mandate
+        /// counts as reported.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6, 5))]
+        #[pallet::call_index(26)]
+        pub fn report_missed_contribution(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            cycle_id: ContributionCycleId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            
+            let cycle = Self::contribution_cycles(club_id, cycle_id)
+                .ok_or(Error::<T>::CycleNotFound)?;
+            
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() > Self::due_by(&cycle),
+                Error::<T>::GracePeriodNotOver
+            );
+            ensure!(
+                T::Members::joined_at(club_id, &account).map_or(false, |joined| joined <= cycle.start_block),
+                Error::<T>::NotObligated
+            );
+            ensure!(
+                !ReportedMisses::<T>::contains_key((club_id, cycle_id, &account)),
+                Error::<T>::AlreadyReported
+            );
+            
+            let contributed = Self::contributions((club_id, cycle_id, &account))
+                .map_or(Zero::zero(), |contribution| contribution.amount);
+            ensure!(
+                contributed < cycle.minimum_contribution,
+                Error::<T>::ContributionNotMissed
+            );
+            
+            let owed = cycle.minimum_contribution.saturating_sub(contributed);
+            let misses = Self::count_missed(&cycle, &account, owed);
+            
+            Self::deposit_event(Event::MissedContributionReported {
+                club_id,
+                cycle_id,
+                account,
+                owed,
+                misses,
+            });
+            
+            Ok(())
+        }
+
+        /// Authorize the treasury to pull `amount` from the caller in every
+        /// contribution cycle of the club, up to `cap` in total
+        ///
+        /// Replaces any earlier mandate of the caller, who must be a member.
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+        #[pallet::call_index(23)]
+        pub fn set_mandate(
+            origin: OriginFor<T>,
+            club_id: ClubId,
+            amount: BalanceOf<T>,
+            cap: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            
+            ensure!(T::Members::is_member(club_id, &account), Error::<T>::NotClubMember);
+            ensure!(
+                !amount.is_zero() && amount <= cap,
+                Error::<T>::InvalidMandate
+            );
+            
+            Mandates::<T>::insert(club_id, &account, ContributionMandate {
+                amount,
+                cap,
+                collected: Zero::zero(),
+            });
+            
+            Self::deposit_event(Event::MandateSet {
                 club_id,
                 account,
                 amount,
+                cap,
             });
             
             Ok(())
         }
 
-        /// Create a withdrawal request
+        /// Revoke the caller's contribution mandate
         #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(1)]
-        pub fn request_withdrawal(
+        #[pallet::call_index(24)]
+        pub fn revoke_mandate(
             origin: OriginFor<T>,
             club_id: ClubId,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            
+            Mandates::<T>::take(club_id, &account)
+                .ok_or(Error::<T>::MandateNotFound)?;
+            
+            Self::deposit_event(Event::MandateRevoked {
+                club_id,
+                account,
+            });
+            
+            Ok(())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(4, 4);
+            
+            // Cancel withdrawal requests that were not approved in time
+            let (expiring, cursor, take_weight) = Self::take_due::<WithdrawalExpiries<T>, _, _>(
+                n,
+                ExpiryCursor::<T>::get(),
+                T::MaxExpiringClubsPerBlock::get(),
+            );
+            ExpiryCursor::<T>::set(cursor);
+            weight = weight.saturating_add(take_weight);
+            for (club_id, withdrawal_ids) in expiring {
+                for withdrawal_id in withdrawal_ids {
+                    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                    let mut withdrawal = match WithdrawalRequests::<T>::get(club_id, withdrawal_id) {
+                        Some(withdrawal) if withdrawal.status == WithdrawalStatus::Pending => withdrawal,
+                        _ => continue,
+                    };
+                    withdrawal.status = WithdrawalStatus::Cancelled;
+                    WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+                    Self::release_earmark(club_id, withdrawal.amount);
+                    Self::release_redemption(&withdrawal);
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 5));
+                    
+                    Self::deposit_event(Event::WithdrawalExpired {
+                        club_id,
+                        withdrawal_id,
+                    });
+                }
+            }
+            
+            // Close cycles whose grace period ended, opening the next cycle of
+            // recurring schedules
+            let per_cycle = T::DbWeight::get().reads_writes(8, 10);
+            let (closing, cursor, take_weight) = Self::take_due::<CycleClosings<T>, _, _>(
+                n,
+                ClosingCursor::<T>::get(),
+                T::MaxClosingsPerBlock::get(),
+            );
+            ClosingCursor::<T>::set(cursor);
+            weight = weight.saturating_add(take_weight);
+            for (club_id, cycle_id) in closing {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                match Self::contribution_cycles(club_id, cycle_id) {
+                    Some(cycle) if cycle.status == CycleStatus::Open => {
+                        Self::do_close_cycle(cycle);
+                        weight = weight.saturating_add(per_cycle);
+                    },
+                    _ => {},
+                }
+            }
+            
+            weight.saturating_add(Self::pay_out_returns(T::PayoutWeightBudget::get()))
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let base = T::DbWeight::get().reads_writes(2, 2);
+            if remaining_weight.any_lt(base) {
+                return Weight::zero();
+            }
+            let per_cycle = T::DbWeight::get().reads_writes(2, 2);
+            let per_mandate = T::DbWeight::get().reads_writes(10, 9);
+            let mut used = base;
+            
+            let mut cursor = CollectionCursor::<T>::take();
+            let now = <frame_system::Pallet<T>>::block_number();
+            
+            while !remaining_weight.any_lt(used.saturating_add(per_cycle)) {
+                let (club_id, cycle_id, raw_key) = match cursor.take() {
+                    Some((club_id, cycle_id, raw_key)) => (club_id, cycle_id, Some(raw_key)),
+                    None => match PendingCollections::<T>::iter_keys().next() {
+                        Some((club_id, cycle_id)) => (club_id, cycle_id, None),
+                        None => break,
+                    },
+                };
+                used = used.saturating_add(per_cycle);
+                let mut cycle = match Self::contribution_cycles(club_id, cycle_id) {
+                    Some(cycle) => cycle,
+                    None => {
+                        PendingCollections::<T>::remove(club_id, cycle_id);
+                        continue;
+                    },
+                };
+                // Mandates not reached before the cycle stopped taking
+                // contributions are recorded as missed
+                let collecting = cycle.status == CycleStatus::Open && now <= cycle.end_block;
+                
+                let mut mandates = match raw_key {
+                    Some(raw_key) => Mandates::<T>::iter_prefix_from(club_id, raw_key.into_inner()),
+                    None => Mandates::<T>::iter_prefix(club_id),
+                };
+                let mut done = true;
+                loop {
+                    if remaining_weight.any_lt(used.saturating_add(per_mandate)) {
+                        done = false;
+                        break;
+                    }
+                    let (account, mandate) = match mandates.next() {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    used = used.saturating_add(per_mandate);
+                    // Mandates of accounts no longer in good standing are not collected
+                    if !T::Members::is_member(club_id, &account) {
+                        continue;
+                    }
+                    if collecting {
+                        Self::collect_mandate(&mut cycle, &account, mandate);
+                    } else {
+                        Self::miss_mandate(&cycle, &account, &mandate);
+                    }
+                }
+                if collecting {
+                    ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+                }
+                
+                if !done {
+                    cursor = BoundedVec::try_from(mandates.last_raw_key().to_vec()).ok()
+                        .map(|raw_key| (club_id, cycle_id, raw_key));
+                    break;
+                }
+                PendingCollections::<T>::remove(club_id, cycle_id);
+            }
+            
+            if let Some(cursor) = cursor {
+                CollectionCursor::<T>::put(cursor);
+            }
+            used
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Recorded treasury balances are backed by their treasury accounts
+        /// and earmarks by the recorded balances
+        #[cfg(any(feature = "try-runtime", test))]
+        pub(crate) fn do_try_state() -> Result<(), &'static str> {
+            for (club_id, recorded) in TreasuryBalances::<T>::iter() {
+                ensure!(
+                    recorded <= T::Currency::free_balance(&Self::treasury_account_id(club_id)),
+                    "recorded treasury balance exceeds the treasury account balance"
+                );
+            }
+            for (club_id, earmarked) in Earmarked::<T>::iter() {
+                ensure!(
+                    earmarked <= Self::treasury_balance(club_id),
+                    "earmarked funds exceed the recorded treasury balance"
+                );
+            }
+            Ok(())
+        }
+
+        /// Generate treasury account ID for a club
+        pub fn treasury_account_id(club_id: ClubId) -> T::AccountId {
+            T::TreasuryPalletId::get().into_sub_account_truncating(club_id)
+        }
+
+        /// Treasury balance of a club that is not earmarked for open withdrawals
+        pub fn available_balance(club_id: ClubId) -> BalanceOf<T> {
+            Self::treasury_balance(club_id).saturating_sub(Self::earmarked(club_id))
+        }
+
+        /// Net asset value of a club: treasury funds that are not earmarked
+        /// plus the value of its positions
+        pub fn net_asset_value(club_id: ClubId) -> BalanceOf<T> {
+            Self::available_balance(club_id).saturating_add(Self::position_value(club_id))
+        }
+
+        /// Value of `units` of a club at its current net asset value
+        pub fn units_value(club_id: ClubId, units: BalanceOf<T>) -> BalanceOf<T> {
+            Self::value_of_units(club_id, units, Self::net_asset_value(club_id))
+        }
+
+        /// Value of `units` of a club whose units in issue are worth `assets`
+        fn value_of_units(club_id: ClubId, units: BalanceOf<T>, assets: BalanceOf<T>) -> BalanceOf<T> {
+            let total = Self::total_units(club_id);
+            if total.is_zero() {
+                return Zero::zero();
+            }
+            
+            let value = multiply_by_rational_with_rounding(
+                units.saturated_into(),
+                assets.saturated_into(),
+                total.saturated_into(),
+                Rounding::Down,
+            )
+            .unwrap_or(0);
+            
+            BalanceOf::<T>::saturated_from(value)
+        }
+
+        /// Units held by `who` in a club and their current value
+        pub fn member_equity(club_id: ClubId, who: &T::AccountId) -> MemberEquity<BalanceOf<T>> {
+            let units = Self::units(club_id, who);
+            MemberEquity {
+                units,
+                value: Self::units_value(club_id, units),
+            }
+        }
+
+        /// Issue club units to `who` for `value` paid into the treasury when
+        /// the net asset value was `nav`
+        ///
+        /// Units are issued one to one while the club has no net assets. The
+        /// first units of a club that already holds assets go to its treasury
+        /// account for those assets, so they are not handed to the first
+        /// contributor.
+        fn mint_units(club_id: ClubId, who: &T::AccountId, value: BalanceOf<T>, nav: BalanceOf<T>) {
+            if Self::total_units(club_id).is_zero() && !nav.is_zero() {
+                Self::mint_club_equity(club_id, nav);
+            }
+            
+            let total = Self::total_units(club_id);
+            let units = if total.is_zero() || nav.is_zero() {
+                value
+            } else {
+                BalanceOf::<T>::saturated_from(
+                    multiply_by_rational_with_rounding(
+                        value.saturated_into(),
+                        total.saturated_into(),
+                        nav.saturated_into(),
+                        Rounding::Down,
+                    )
+                    .unwrap_or(0),
+                )
+            };
+            
+            Units::<T>::mutate(club_id, who, |held| *held = held.saturating_add(units));
+            TotalUnits::<T>::insert(club_id, total.saturating_add(units));
+            
+            Self::deposit_event(Event::UnitsMinted {
+                club_id,
+                account: who.clone(),
+                units,
+                value,
+            });
+        }
+
+        /// Issue `equity` units to the club's own treasury account for assets
+        /// it held before any units were in issue
+        pub(crate) fn mint_club_equity(club_id: ClubId, equity: BalanceOf<T>) {
+            let treasury = Self::treasury_account_id(club_id);
+            Units::<T>::mutate(club_id, &treasury, |held| *held = held.saturating_add(equity));
+            TotalUnits::<T>::mutate(club_id, |total| *total = total.saturating_add(equity));
+            
+            Self::deposit_event(Event::UnitsMinted {
+                club_id,
+                account: treasury,
+                units: equity,
+                value: equity,
+            });
+        }
+
+        /// Take `units` held by `who` out of issue and request a withdrawal of
+        /// `value` to them
+        ///
+        /// The withdrawal is earmarked, charged against the spending limits
+        /// and needs the signers' approval like any other.
+        fn request_redemption(
+            club_id: ClubId,
+            who: T::AccountId,
+            units: BalanceOf<T>,
+            value: BalanceOf<T>,
+        ) -> DispatchResult {
+            let held = Self::units(club_id, &who);
+            ensure!(
+                units <= held,
+                Error::<T>::InsufficientUnits
+            );
+            let signer_set = Self::signer_set(club_id).ok_or(Error::<T>::NoSignerSet)?;
+            
+            let withdrawal_id = Self::do_request_withdrawal(
+                club_id,
+                &signer_set,
+                who.clone(),
+                who.clone(),
+                value,
+                Zero::zero(),
+                BoundedVec::new(),
+            )?;
+            Units::<T>::insert(club_id, &who, held.saturating_sub(units));
+            TotalUnits::<T>::mutate(club_id, |total| *total = total.saturating_sub(units));
+            Redemptions::<T>::insert(club_id, withdrawal_id, units);
+            
+            Self::deposit_event(Event::RedemptionRequested {
+                club_id,
+                account: who,
+                withdrawal_id,
+                units,
+                value,
+            });
+            
+            Ok(())
+        }
+
+        /// Burn the units of a redemption whose withdrawal was executed and
+        /// take the paid out value out of the member's contribution to the
+        /// open cycle
+        fn complete_redemption(withdrawal: &WithdrawalRequest<T>) {
+            let units = match Redemptions::<T>::take(withdrawal.club_id, withdrawal.id) {
+                Some(units) => units,
+                None => return,
+            };
+            Self::reduce_contribution(withdrawal.club_id, &withdrawal.recipient, withdrawal.amount);
+            
+            Self::deposit_event(Event::UnitsBurned {
+                club_id: withdrawal.club_id,
+                account: withdrawal.recipient.clone(),
+                units,
+                value: withdrawal.amount,
+            });
+        }
+
+        /// Return the units of a redemption whose withdrawal was dropped
+        fn release_redemption(withdrawal: &WithdrawalRequest<T>) {
+            let units = match Redemptions::<T>::take(withdrawal.club_id, withdrawal.id) {
+                Some(units) => units,
+                None => return,
+            };
+            Units::<T>::mutate(withdrawal.club_id, &withdrawal.recipient, |held| *held = held.saturating_add(units));
+            TotalUnits::<T>::mutate(withdrawal.club_id, |total| *total = total.saturating_add(units));
+            
+            Self::deposit_event(Event::RedemptionReleased {
+                club_id: withdrawal.club_id,
+                account: withdrawal.recipient.clone(),
+                withdrawal_id: withdrawal.id,
+                units,
+            });
+        }
+
+        /// Take up to `amount` out of what `who` contributed to the club's open
+        /// cycle, latest contributions first, so redeemed funds earn no returns
+        fn reduce_contribution(club_id: ClubId, who: &T::AccountId, amount: BalanceOf<T>) {
+            let mut cycle = match Self::active_cycle(club_id).and_then(|cycle_id| Self::contribution_cycles(club_id, cycle_id)) {
+                Some(cycle) if cycle.status == CycleStatus::Open => cycle,
+                _ => return,
+            };
+            let reduced = Contributions::<T>::mutate_exists((club_id, cycle.id, who), |maybe_contribution| {
+                let contribution = match maybe_contribution {
+                    Some(contribution) => contribution,
+                    None => return Zero::zero(),
+                };
+                let reduced = contribution.amount.min(amount);
+                contribution.amount = contribution.amount.saturating_sub(reduced);
+                if contribution.amount.is_zero() {
+                    *maybe_contribution = None;
+                }
+                reduced
+            });
+            if reduced.is_zero() {
+                return;
+            }
+            
+            let time_weighted = Self::cycle_share_mode(club_id, cycle.id) == ShareMode::TimeWeighted;
+            let mut left = reduced;
+            let mut weight = 0u128;
+            ContributionLots::<T>::mutate((club_id, cycle.id, who), |lots| {
+                while let Some(lot) = lots.last_mut() {
+                    if left.is_zero() {
+                        break;
+                    }
+                    let taken = lot.amount.min(left);
+                    if time_weighted {
+                        let part = ContributionLot { amount: taken, contributed_at: lot.contributed_at };
+                        weight = weight.saturating_add(Self::lot_weight(&cycle, &part));
+                    }
+                    lot.amount = lot.amount.saturating_sub(taken);
+                    left = left.saturating_sub(taken);
+                    if lot.amount.is_zero() {
+                        lots.pop();
+                    }
+                }
+            });
+            if weight > 0 {
+                CycleShareWeights::<T>::mutate(club_id, cycle.id, |total| *total = total.saturating_sub(weight));
+            }
+            
+            cycle.total_contributions = cycle.total_contributions.saturating_sub(reduced);
+            ContributionCycles::<T>::insert(club_id, cycle.id, &cycle);
+        }
+
+        /// Release funds earmarked for a withdrawal that was executed or dropped
+        fn release_earmark(club_id: ClubId, amount: BalanceOf<T>) {
+            Earmarked::<T>::mutate(club_id, |earmarked| *earmarked = earmarked.saturating_sub(amount));
+        }
+
+        /// Store a withdrawal request of `amount` to `recipient` carrying
+        /// `signatures` and earmark its amount
+        fn do_request_withdrawal(
+            club_id: ClubId,
+            signer_set: &SignerSet<T>,
+            requester: T::AccountId,
             recipient: T::AccountId,
             amount: BalanceOf<T>,
             unlock_delay: BlockNumberFor<T>,
-        ) -> DispatchResult {
-            let requester = ensure_signed(origin)?;
-            
-            let balance = Self::treasury_balance(club_id);
-            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+            signatures: BoundedVec<T::AccountId, T::MaxSigners>,
+        ) -> Result<WithdrawalId, DispatchError> {
+            ensure!(Self::available_balance(club_id) >= amount, Error::<T>::InsufficientBalance);
             
             let withdrawal_id = Self::withdrawal_count(club_id);
             let new_count = withdrawal_id.saturating_add(1);
@@ -316,22 +2949,32 @@ pub mod pallet {
             let now = <frame_system::Pallet<T>>::block_number();
             let unlock_at = now.saturating_add(unlock_delay);
             
-            let mut signatures = BoundedVec::new();
-            signatures.try_push(requester.clone())
-                .map_err(|_| Error::<T>::InsufficientSignatures)?;
+            let status = if signatures.len() >= signer_set.threshold as usize {
+                WithdrawalStatus::Approved
+            } else {
+                WithdrawalStatus::Pending
+            };
             
             let withdrawal = WithdrawalRequest {
                 id: withdrawal_id,
                 club_id,
+                requester: requester.clone(),
                 recipient: recipient.clone(),
                 amount,
                 unlock_at,
-                status: WithdrawalStatus::Pending,
+                status,
                 signatures,
+                rejections: BoundedVec::new(),
+                signer_set_version: signer_set.version,
                 created_at: now,
             };
             
             WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            Earmarked::<T>::mutate(club_id, |earmarked| *earmarked = earmarked.saturating_add(amount));
+            WithdrawalExpiries::<T>::try_mutate(now.saturating_add(T::WithdrawalExpiry::get()), club_id, |expiring| {
+                expiring.try_push(withdrawal_id)
+                    .map_err(|_| Error::<T>::TooManyExpiringWithdrawals)
+            })?;
             
             Self::deposit_event(Event::WithdrawalRequested {
                 club_id,
@@ -340,112 +2983,92 @@ pub mod pallet {
                 amount,
             });
             
-            Ok(())
+            Ok(withdrawal_id)
         }
 
-        /// Approve a withdrawal request
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(2)]
-        pub fn approve_withdrawal(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            withdrawal_id: WithdrawalId,
-        ) -> DispatchResult {
-            let approver = ensure_signed(origin)?;
-            
-            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
-                .ok_or(Error::<T>::WithdrawalNotFound)?;
-            
-            ensure!(
-                withdrawal.status == WithdrawalStatus::Pending,
-                Error::<T>::WithdrawalNotPending
-            );
-            
-            ensure!(
-                !withdrawal.signatures.contains(&approver),
-                Error::<T>::AlreadySigned
-            );
-            
-            withdrawal.signatures.try_push(approver.clone())
-                .map_err(|_| Error::<T>::InsufficientSignatures)?;
+        /// Record `amount` leaving the treasury against the club's spending limits
+        ///
+        /// Spends beyond a limit draw on the allowance granted by governance
+        /// and fail if it does not cover them.
+        fn charge_spending(club_id: ClubId, amount: BalanceOf<T>) -> DispatchResult {
+            let limit = match Self::spending_limit(club_id) {
+                Some(limit) => limit,
+                None => return Ok(()),
+            };
             
-            // Check if we have enough signatures
-            if withdrawal.signatures.len() >= T::MinSignatures::get() as usize {
-                withdrawal.status = WithdrawalStatus::Approved;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let (mut period_start, mut spent) = Self::spending_window(club_id);
+            if now >= period_start.saturating_add(limit.period) {
+                period_start = now;
+                spent = Zero::zero();
             }
+            spent = spent.saturating_add(amount);
             
-            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
-            
-            Self::deposit_event(Event::WithdrawalApproved {
-                club_id,
-                withdrawal_id,
-                approver,
-            });
+            if amount > limit.max_per_withdrawal || spent > limit.period_cap {
+                let allowance = Self::spending_override(club_id);
+                ensure!(allowance >= amount, Error::<T>::SpendingLimitExceeded);
+                SpendingOverrides::<T>::insert(club_id, allowance.saturating_sub(amount));
+            }
             
+            SpendingWindows::<T>::insert(club_id, (period_start, spent));
             Ok(())
         }
 
-        /// Execute an approved withdrawal
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(3)]
-        pub fn execute_withdrawal(
-            origin: OriginFor<T>,
+        /// Validate and store a new ROSCA
+        fn do_open_rosca(
             club_id: ClubId,
-            withdrawal_id: WithdrawalId,
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            
-            let mut withdrawal = WithdrawalRequests::<T>::get(club_id, withdrawal_id)
-                .ok_or(Error::<T>::WithdrawalNotFound)?;
-            
+            participants: Vec<T::AccountId>,
+            contribution: BalanceOf<T>,
+            round_length: BlockNumberFor<T>,
+            order: PayoutOrder,
+        ) -> Result<RoscaId, DispatchError> {
             ensure!(
-                withdrawal.status == WithdrawalStatus::Approved,
-                Error::<T>::WithdrawalNotPending
+                participants.len() >= 2
+                    && participants.iter().enumerate().all(|(i, who)| !participants[..i].contains(who))
+                    && contribution >= T::MinContribution::get()
+                    && !round_length.is_zero(),
+                Error::<T>::InvalidRosca
             );
-            
-            let now = <frame_system::Pallet<T>>::block_number();
             ensure!(
-                now >= withdrawal.unlock_at,
-                Error::<T>::WithdrawalNotReady
+                participants.iter().all(|who| T::Members::is_member(club_id, who)),
+                Error::<T>::NotClubMember
             );
             
-            let balance = Self::treasury_balance(club_id);
-            ensure!(balance >= withdrawal.amount, Error::<T>::InsufficientBalance);
-            
-            // Transfer funds
-            T::Currency::transfer(
-                &Self::treasury_account_id(club_id),
-                &withdrawal.recipient,
-                withdrawal.amount,
-                ExistenceRequirement::AllowDeath,
-            )?;
-            
-            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_sub(withdrawal.amount));
-            
-            withdrawal.status = WithdrawalStatus::Executed;
-            WithdrawalRequests::<T>::insert(club_id, withdrawal_id, &withdrawal);
+            let rosca_id = Self::rosca_count(club_id);
+            RoscaCount::<T>::insert(club_id, rosca_id.saturating_add(1));
             
-            Self::deposit_event(Event::WithdrawalExecuted {
+            let rosca = Rosca {
+                id: rosca_id,
                 club_id,
-                withdrawal_id,
-                recipient: withdrawal.recipient,
-                amount: withdrawal.amount,
+                contribution,
+                round_length,
+                order: order.clone(),
+                participants: BoundedVec::try_from(participants)
+                    .map_err(|_| Error::<T>::InvalidRosca)?,
+                round: 0,
+                round_start: <frame_system::Pallet<T>>::block_number(),
+                pot: Zero::zero(),
+                paid_out: 0,
+                status: RoscaStatus::Active,
+            };
+            Roscas::<T>::insert(club_id, rosca_id, &rosca);
+            
+            Self::deposit_event(Event::RoscaOpened {
+                club_id,
+                rosca_id,
+                contribution,
+                order,
             });
             
-            Ok(())
+            Ok(rosca_id)
         }
 
-        /// Open a new contribution cycle
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
-        #[pallet::call_index(4)]
-        pub fn open_contribution_cycle(
-            origin: OriginFor<T>,
+        /// Open a new contribution cycle and schedule its closing
+        fn do_open_cycle(
             club_id: ClubId,
-            contribution_period: Option<BlockNumberFor<T>>,
-            minimum_contribution: Option<BalanceOf<T>>,
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            
+            period: BlockNumberFor<T>,
+            min_contrib: BalanceOf<T>,
+        ) -> Result<ContributionCycleId, DispatchError> {
             // Check no active cycle exists
             ensure!(
                 !ActiveCycle::<T>::contains_key(club_id),
@@ -457,8 +3080,6 @@ pub mod pallet {
             CycleCount::<T>::insert(club_id, new_count);
             
             let now = <frame_system::Pallet<T>>::block_number();
-            let period = contribution_period.unwrap_or(T::DefaultContributionPeriod::get());
-            let min_contrib = minimum_contribution.unwrap_or(T::MinContribution::get());
             let end_block = now.saturating_add(period);
             
             let cycle = ContributionCycle {
@@ -470,10 +3091,22 @@ pub mod pallet {
                 returns: Zero::zero(),
                 status: CycleStatus::Open,
                 minimum_contribution: min_contrib,
+                claimed_returns: Zero::zero(),
+                claim_deadline: None,
             };
             
             ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
             ActiveCycle::<T>::insert(club_id, cycle_id);
+            if let Some(terms) = Self::payment_terms(club_id) {
+                CyclePaymentTerms::<T>::insert(club_id, cycle_id, terms);
+            }
+            CycleShareModes::<T>::insert(club_id, cycle_id, Self::share_mode(club_id));
+            
+            CycleClosings::<T>::insert(Self::due_by(&cycle).saturating_add(One::one()), club_id, cycle_id);
+            
+            if Mandates::<T>::iter_prefix(club_id).next().is_some() {
+                PendingCollections::<T>::insert(club_id, cycle_id, ());
+            }
             
             Self::deposit_event(Event::CycleOpened {
                 club_id,
@@ -481,246 +3114,587 @@ pub mod pallet {
                 end_block,
             });
             
-            Ok(())
+            Ok(cycle_id)
         }
 
-        /// Contribute to the active cycle
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
-        #[pallet::call_index(5)]
-        pub fn contribute(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            amount: BalanceOf<T>,
-        ) -> DispatchResult {
-            let contributor = ensure_signed(origin)?;
+        /// Close an open cycle and, for clubs on a recurring schedule, open
+        /// the next one
+        fn do_close_cycle(mut cycle: ContributionCycle<T>) {
+            let club_id = cycle.club_id;
+            cycle.status = CycleStatus::Closed;
+            ContributionCycles::<T>::insert(club_id, cycle.id, &cycle);
+            ActiveCycle::<T>::remove(club_id);
             
-            let cycle_id = Self::active_cycle(club_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
+            Self::deposit_event(Event::CycleClosed {
+                club_id,
+                cycle_id: cycle.id,
+                total_contributions: cycle.total_contributions,
+            });
             
-            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
+            if Self::recurring_cycles(club_id) {
+                let period = cycle.end_block.saturating_sub(cycle.start_block);
+                let opened = frame_support::storage::with_storage_layer(|| {
+                    Self::do_open_cycle(club_id, period, cycle.minimum_contribution)
+                });
+                if let Err(error) = opened {
+                    Self::deposit_event(Event::CycleRolloverFailed {
+                        club_id,
+                        cycle_id: cycle.id,
+                        error,
+                    });
+                }
+            }
+        }
+
+        /// Share of a distributed cycle's returns owed to `account`, who
+        /// contributed `contributed` in total
+        fn return_share(
+            cycle: &ContributionCycle<T>,
+            account: &T::AccountId,
+            contributed: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let (part, total) = match Self::cycle_share_mode(cycle.club_id, cycle.id) {
+                ShareMode::ProRata => (
+                    contributed.saturated_into::<u128>(),
+                    cycle.total_contributions.saturated_into::<u128>(),
+                ),
+                ShareMode::TimeWeighted => (
+                    Self::contribution_lots((cycle.club_id, cycle.id, account))
+                        .iter()
+                        .fold(0u128, |weight, lot| weight.saturating_add(Self::lot_weight(cycle, lot))),
+                    Self::cycle_share_weight(cycle.club_id, cycle.id),
+                ),
+            };
+            if total == 0 {
+                return Zero::zero();
+            }
             
-            ensure!(
-                cycle.status == CycleStatus::Open,
-                Error::<T>::CycleNotOpen
-            );
+            // share = part * returns / total, without overflowing the product
+            let share = multiply_by_rational_with_rounding(part, cycle.returns.saturated_into(), total, Rounding::Down)
+                .unwrap_or(0);
             
-            let now = <frame_system::Pallet<T>>::block_number();
-            ensure!(
-                now <= cycle.end_block,
-                Error::<T>::CycleNotOpen
-            );
+            BalanceOf::<T>::saturated_from(share)
+        }
+
+        /// Time-weighted share of a lot: its amount times the blocks left in
+        /// the cycle when it was made, counting at least the block itself
+        fn lot_weight(cycle: &ContributionCycle<T>, lot: &ContributionLot<T>) -> u128 {
+            let blocks: u128 = cycle.end_block
+                .saturating_sub(lot.contributed_at)
+                .saturating_add(One::one())
+                .saturated_into();
+            
+            lot.amount.saturated_into::<u128>().saturating_mul(blocks)
+        }
+
+        /// Pay `share` of a distributed cycle's returns from the treasury to
+        /// `account` and record it as claimed
+        ///
+        /// The caller stores the updated cycle.
+        fn pay_return_share(
+            cycle: &mut ContributionCycle<T>,
+            account: &T::AccountId,
+            share: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::Currency::transfer(
+                &Self::treasury_account_id(cycle.club_id),
+                account,
+                share,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            
+            TreasuryBalances::<T>::mutate(cycle.club_id, |balance| *balance = balance.saturating_sub(share));
+            Self::release_earmark(cycle.club_id, share);
+            ClaimedReturns::<T>::insert((cycle.club_id, cycle.id, account), share);
+            cycle.claimed_returns = cycle.claimed_returns.saturating_add(share);
+            
+            Self::deposit_event(Event::ReturnsClaimed {
+                club_id: cycle.club_id,
+                cycle_id: cycle.id,
+                account: account.clone(),
+                amount: share,
+            });
+            
+            Ok(())
+        }
+
+        /// Pay out the returns of queued cycles, spending at most `budget`
+        ///
+        /// Resumes from the stored cursor and leaves a new one behind when the
+        /// budget runs out before a cycle is done. A cycle is swept once all
+        /// its contributors were paid; if some could not be, it stays open to
+        /// their claims until the deadline.
+        fn pay_out_returns(budget: Weight) -> Weight {
+            let base = T::DbWeight::get().reads_writes(2, 2);
+            if budget.any_lt(base) {
+                return Weight::zero();
+            }
+            let per_cycle = T::DbWeight::get().reads_writes(2, 2);
+            let per_contributor = T::DbWeight::get().reads_writes(6, 5);
+            let mut used = base;
+            
+            let mut cursor = PayoutCursor::<T>::take();
+            
+            while !budget.any_lt(used.saturating_add(per_cycle)) {
+                let (club_id, cycle_id, mut unpaid_accounts, raw_key) = match cursor.take() {
+                    Some((club_id, cycle_id, unpaid_accounts, raw_key)) => {
+                        (club_id, cycle_id, unpaid_accounts, Some(raw_key))
+                    },
+                    None => match PendingPayouts::<T>::iter_keys().next() {
+                        Some((club_id, cycle_id)) => (club_id, cycle_id, 0, None),
+                        None => break,
+                    },
+                };
+                used = used.saturating_add(per_cycle);
+                // Cycles swept while queued have nothing left to pay
+                let mut cycle = match Self::contribution_cycles(club_id, cycle_id) {
+                    Some(cycle) if cycle.status == CycleStatus::Distributed => cycle,
+                    _ => {
+                        PendingPayouts::<T>::remove(club_id, cycle_id);
+                        continue;
+                    },
+                };
+                
+                let mut contributions = match raw_key {
+                    Some(raw_key) => Contributions::<T>::iter_prefix_from((club_id, cycle_id), raw_key.into_inner()),
+                    None => Contributions::<T>::iter_prefix((club_id, cycle_id)),
+                };
+                let mut paid_accounts = 0u32;
+                let mut amount: BalanceOf<T> = Zero::zero();
+                let mut done = true;
+                loop {
+                    if budget.any_lt(used.saturating_add(per_contributor)) {
+                        done = false;
+                        break;
+                    }
+                    let (account, contribution) = match contributions.next() {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    used = used.saturating_add(per_contributor);
+                    
+                    if ClaimedReturns::<T>::contains_key((club_id, cycle_id, &account)) {
+                        continue;
+                    }
+                    let share = Self::return_share(&cycle, &account, contribution.amount);
+                    if share.is_zero() {
+                        continue;
+                    }
+                    let paid = frame_support::storage::with_storage_layer(|| {
+                        Self::pay_return_share(&mut cycle, &account, share)
+                    });
+                    if paid.is_ok() {
+                        paid_accounts = paid_accounts.saturating_add(1);
+                        amount = amount.saturating_add(share);
+                    } else {
+                        unpaid_accounts = unpaid_accounts.saturating_add(1);
+                    }
+                }
+                
+                if paid_accounts > 0 {
+                    Self::deposit_event(Event::ReturnsPayoutProgress {
+                        club_id,
+                        cycle_id,
+                        paid_accounts,
+                        amount,
+                    });
+                }
+                
+                if !done {
+                    ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+                    cursor = BoundedVec::try_from(contributions.last_raw_key().to_vec()).ok()
+                        .map(|raw_key| (club_id, cycle_id, unpaid_accounts, raw_key));
+                    break;
+                }
+                
+                // Unpaid shares are left for their owners to claim, and the
+                // rest is released when the cycle is swept after the deadline
+                let dust = if unpaid_accounts == 0 {
+                    let dust = cycle.returns.saturating_sub(cycle.claimed_returns);
+                    Self::release_earmark(club_id, dust);
+                    cycle.status = CycleStatus::Swept;
+                    dust
+                } else {
+                    Zero::zero()
+                };
+                ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+                PendingPayouts::<T>::remove(club_id, cycle_id);
+                
+                Self::deposit_event(Event::ReturnsPayoutCompleted {
+                    club_id,
+                    cycle_id,
+                    unpaid_accounts,
+                    dust,
+                });
+            }
             
+            if let Some(cursor) = cursor {
+                PayoutCursor::<T>::put(cursor);
+            }
+            used
+        }
+
+        /// Take up to `limit` entries of a queue keyed by block that are due by
+        /// `now`, starting from `cursor`, the first block not fully handled
+        ///
+        /// Each block passed counts against the limit like an entry, so that
+        /// catching up on a backlog stays bounded as well. Returns the entries,
+        /// the cursor to resume from if entries due by `now` remain, and the
+        /// weight of reading and removing them.
+        fn take_due<Queue, K, V>(
+            now: BlockNumberFor<T>,
+            cursor: Option<BlockNumberFor<T>>,
+            limit: u32,
+        ) -> (Vec<(K, V)>, Option<BlockNumberFor<T>>, Weight)
+        where
+            Queue: IterableStorageDoubleMap<BlockNumberFor<T>, K, V>,
+            K: FullCodec,
+            V: FullCodec,
+        {
+            let mut at = cursor.unwrap_or(now);
+            let mut budget = limit as usize;
+            let mut entries = Vec::new();
+            let mut weight = Weight::zero();
+            while at <= now && budget > 0 {
+                let taken: Vec<(K, V)> = Queue::drain_prefix(at).take(budget).collect();
+                let count = taken.len();
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2 + count as u64, count as u64));
+                entries.extend(taken);
+                if count == budget && Queue::iter_prefix(at).next().is_some() {
+                    return (entries, Some(at), weight);
+                }
+                budget -= count;
+                at = at.saturating_add(One::one());
+                budget = budget.saturating_sub(1);
+            }
+            (entries, if at <= now { Some(at) } else { None }, weight)
+        }
+
+        /// Last block at which contributions to a cycle are accepted
+        fn due_by(cycle: &ContributionCycle<T>) -> BlockNumberFor<T> {
+            Self::cycle_payment_terms(cycle.club_id, cycle.id)
+                .map_or(cycle.end_block, |terms| cycle.end_block.saturating_add(terms.grace_period))
+        }
+
+        /// Move `amount` from `contributor` into the treasury as a contribution
+        /// to `cycle`
+        ///
+        /// The caller stores the updated cycle.
+        fn record_contribution(
+            cycle: &mut ContributionCycle<T>,
+            contributor: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
             ensure!(
                 amount >= cycle.minimum_contribution,
                 Error::<T>::ContributionBelowMinimum
             );
             
-            // Transfer funds to treasury
+            let club_id = cycle.club_id;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let lot = ContributionLot {
+                amount,
+                contributed_at: now,
+            };
+            if Self::cycle_share_mode(club_id, cycle.id) == ShareMode::TimeWeighted {
+                let weight = Self::lot_weight(cycle, &lot);
+                CycleShareWeights::<T>::mutate(club_id, cycle.id, |total| *total = total.saturating_add(weight));
+            }
+            ContributionLots::<T>::try_append((club_id, cycle.id, contributor), lot)
+                .map_err(|_| Error::<T>::TooManyContributionLots)?;
+            
+            let nav = Self::net_asset_value(club_id);
             T::Currency::transfer(
-                &contributor,
+                contributor,
                 &Self::treasury_account_id(club_id),
                 amount,
                 ExistenceRequirement::KeepAlive,
             )?;
             
-            // Update or create contribution record
-            let existing_contrib = Self::contributions(club_id, cycle_id, &contributor);
-            if let Some(mut contrib) = existing_contrib {
-                contrib.amount = contrib.amount.saturating_add(amount);
-                Contributions::<T>::insert((club_id, cycle_id, &contributor), &contrib);
-            } else {
-                let contrib = Contribution {
-                    contributor: contributor.clone(),
-                    amount,
-                    contributed_at: now,
-                };
-                Contributions::<T>::insert((club_id, cycle_id, &contributor), &contrib);
-            }
+            Contributions::<T>::mutate((club_id, cycle.id, contributor), |contribution| {
+                match contribution {
+                    Some(contribution) => contribution.amount = contribution.amount.saturating_add(amount),
+                    None => *contribution = Some(Contribution {
+                        contributor: contributor.clone(),
+                        amount,
+                        contributed_at: now,
+                    }),
+                }
+            });
             
-            // Update cycle totals
             cycle.total_contributions = cycle.total_contributions.saturating_add(amount);
             TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(amount));
-            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            Self::mint_units(club_id, contributor, amount, nav);
             
             Self::deposit_event(Event::ContributionMade {
                 club_id,
-                cycle_id,
-                contributor,
+                cycle_id: cycle.id,
+                contributor: contributor.clone(),
                 amount,
             });
             
             Ok(())
         }
 
-        /// Close the active contribution cycle
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        #[pallet::call_index(6)]
-        pub fn close_cycle(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            
-            let cycle_id = Self::active_cycle(club_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
-            
-            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
+        /// Pull the contribution due under a mandate, recording it as missed
+        /// if it cannot be paid
+        fn collect_mandate(
+            cycle: &mut ContributionCycle<T>,
+            account: &T::AccountId,
+            mut mandate: ContributionMandate<T>,
+        ) {
+            let amount = mandate.amount.min(mandate.cap.saturating_sub(mandate.collected));
+            if amount.is_zero() {
+                return;
+            }
             
-            ensure!(
-                cycle.status == CycleStatus::Open,
-                Error::<T>::CycleNotOpen
-            );
+            let collected = frame_support::storage::with_storage_layer(|| {
+                Self::record_contribution(cycle, account, amount)
+            });
+            if collected.is_ok() {
+                mandate.collected = mandate.collected.saturating_add(amount);
+                Mandates::<T>::insert(cycle.club_id, account, mandate);
+            } else {
+                Self::record_missed(cycle, account, amount);
+            }
+        }
+
+        /// Record the contribution due under a mandate that was not collected
+        /// before the cycle stopped taking contributions, less what the
+        /// member paid in by hand
+        fn miss_mandate(
+            cycle: &ContributionCycle<T>,
+            account: &T::AccountId,
+            mandate: &ContributionMandate<T>,
+        ) {
+            let due = mandate.amount.min(mandate.cap.saturating_sub(mandate.collected));
+            let contributed = Self::contributions((cycle.club_id, cycle.id, account))
+                .map_or(Zero::zero(), |contribution| contribution.amount);
+            let amount = due.saturating_sub(contributed);
+            if !amount.is_zero() {
+                Self::record_missed(cycle, account, amount);
+            }
+        }
+
+        /// Record a mandated contribution of `amount` that was not made
+        fn record_missed(cycle: &ContributionCycle<T>, account: &T::AccountId, amount: BalanceOf<T>) {
+            Self::count_missed(cycle, account, amount);
+            Self::deposit_event(Event::ContributionMissed {
+                club_id: cycle.club_id,
+                cycle_id: cycle.id,
+                account: account.clone(),
+                amount,
+            });
+        }
+
+        /// Add `amount` to what `account` owes for `cycle` and, the first time
+        /// in the cycle, count the miss against their standing
+        ///
+        /// Returns the number of cycles `account` missed in the club.
+        fn count_missed(cycle: &ContributionCycle<T>, account: &T::AccountId, amount: BalanceOf<T>) -> u32 {
+            let club_id = cycle.club_id;
+            MissedContributions::<T>::mutate((club_id, cycle.id, account), |missed| {
+                *missed = missed.saturating_add(amount)
+            });
+            if ReportedMisses::<T>::contains_key((club_id, cycle.id, account)) {
+                return Self::missed_payment_count(club_id, account);
+            }
             
-            let now = <frame_system::Pallet<T>>::block_number();
+            ReportedMisses::<T>::insert((club_id, cycle.id, account), ());
+            let misses = MissedPaymentCount::<T>::mutate(club_id, account, |count| {
+                *count = count.saturating_add(1);
+                *count
+            });
+            T::Members::note_missed_contribution(club_id, account, misses);
+            misses
+        }
+
+        /// Make `discount` the leading bid of the current round if it beats it
+        fn place_bid(rosca: &Rosca<T>, bidder: T::AccountId, discount: BalanceOf<T>) -> DispatchResult {
+            let full_pot = rosca.contribution.saturating_mul((rosca.participants.len() as u32).into());
             ensure!(
-                now > cycle.end_block,
-                Error::<T>::CycleNotClosed
+                discount < full_pot
+                    && Self::leading_bid(rosca.club_id, rosca.id).map_or(true, |(_, leading)| discount > leading),
+                Error::<T>::InvalidBid
             );
             
-            cycle.status = CycleStatus::Closed;
-            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
-            ActiveCycle::<T>::remove(club_id);
+            LeadingBids::<T>::insert(rosca.club_id, rosca.id, (bidder.clone(), discount));
             
-            Self::deposit_event(Event::CycleClosed {
-                club_id,
-                cycle_id,
-                total_contributions: cycle.total_contributions,
+            Self::deposit_event(Event::RoundBidPlaced {
+                club_id: rosca.club_id,
+                rosca_id: rosca.id,
+                round: rosca.round,
+                bidder,
+                discount,
             });
             
             Ok(())
         }
 
-        /// Set returns for a closed cycle and distribute them
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(3))]
-        #[pallet::call_index(7)]
-        pub fn distribute_returns(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-            returns: BalanceOf<T>,
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            
-            let mut cycle = Self::contribution_cycles(club_id, cycle_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
-            
-            ensure!(
-                cycle.status == CycleStatus::Closed,
-                Error::<T>::CycleNotClosed
-            );
-            
-            ensure!(
-                returns > Zero::zero(),
-                Error::<T>::NoReturnsToDistribute
-            );
+        /// Share the discount of an auction round equally among `participants`
+        ///
+        /// The remainder of the division stays in the treasury.
+        fn pay_dividends(rosca: &Rosca<T>, participants: &[T::AccountId], discount: BalanceOf<T>) -> DispatchResult {
+            if discount.is_zero() || participants.is_empty() {
+                return Ok(());
+            }
             
-            cycle.returns = returns;
-            cycle.status = CycleStatus::Distributed;
-            ContributionCycles::<T>::insert(club_id, cycle_id, &cycle);
+            let count = participants.len() as u32;
+            let per_participant = discount / count.into();
+            if per_participant.is_zero() {
+                return Ok(());
+            }
             
-            // Distribute returns proportionally: share = (user_contribution / total_contribution) * returns
-            if cycle.total_contributions > Zero::zero() {
-                // Iterate through all contributions and distribute
-                // Note: In production, this would need to be optimized for large numbers of contributors
-                // For MVP, we'll use a simplified approach
-                let treasury_account = Self::treasury_account_id(club_id);
-                let total = cycle.total_contributions;
-                
-                // Calculate distribution for each contributor
-                // This is a simplified version - in production, you'd iterate through all contributions
-                // For MVP, we store the returns in the cycle and let users claim them individually
-                // via a separate claim function
+            let treasury = Self::treasury_account_id(rosca.club_id);
+            for participant in participants {
+                T::Currency::transfer(&treasury, participant, per_participant, ExistenceRequirement::AllowDeath)?;
             }
+            TreasuryBalances::<T>::mutate(rosca.club_id, |balance| {
+                *balance = balance.saturating_sub(per_participant.saturating_mul(count.into()))
+            });
             
-            Self::deposit_event(Event::ReturnsDistributed {
-                club_id,
-                cycle_id,
-                total_returns: returns,
+            Self::deposit_event(Event::AuctionDividendsPaid {
+                club_id: rosca.club_id,
+                rosca_id: rosca.id,
+                round: rosca.round,
+                per_participant,
+                participants: count,
             });
             
             Ok(())
         }
 
-        /// Claim returns from a distributed cycle
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
-        #[pallet::call_index(8)]
-        pub fn claim_returns(
-            origin: OriginFor<T>,
-            club_id: ClubId,
-            cycle_id: ContributionCycleId,
-        ) -> DispatchResult {
-            let claimant = ensure_signed(origin)?;
-            
-            let cycle = Self::contribution_cycles(club_id, cycle_id)
-                .ok_or(Error::<T>::CycleNotFound)?;
-            
-            ensure!(
-                cycle.status == CycleStatus::Distributed,
-                Error::<T>::CycleNotClosed
-            );
-            
-            let contribution = Self::contributions(club_id, cycle_id, &claimant)
-                .ok_or(Error::<T>::CycleNotFound)?;
+        /// Pick the recipient of a ROSCA round and the discount they accept
+        fn round_recipient(
+            rosca: &Rosca<T>,
+            eligible: &[T::AccountId],
+            leading_bid: Option<(T::AccountId, BalanceOf<T>)>,
+        ) -> Result<Option<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+            if eligible.is_empty() {
+                return Ok(None);
+            }
             
-            // Calculate share: (user_contribution / total_contribution) * returns
-            let share = if cycle.total_contributions > Zero::zero() {
-                // Use fixed point arithmetic: share = (contribution * returns) / total
-                // For u128, we can use checked arithmetic
-                let contrib_u128: u128 = contribution.amount.saturated_into();
-                let returns_u128: u128 = cycle.returns.saturated_into();
-                let total_u128: u128 = cycle.total_contributions.saturated_into();
-                
-                let share_u128 = contrib_u128
-                    .checked_mul(returns_u128)
-                    .and_then(|x| x.checked_div(total_u128))
-                    .unwrap_or(0);
-                
-                BalanceOf::<T>::saturated_from(share_u128)
-            } else {
-                Zero::zero()
-            };
+            match rosca.order {
+                PayoutOrder::Fixed => Ok(Some((eligible[0].clone(), Zero::zero()))),
+                PayoutOrder::Lottery => {
+                    // Only randomness that nobody knew while contributions to
+                    // the round could still be made decides the draw
+                    let last_block = rosca.round_start
+                        .saturating_add(rosca.round_length)
+                        .saturating_sub(One::one());
+                    let subject = (b"ikub/rosca", rosca.club_id, rosca.id, rosca.round).encode();
+                    let (seed, known_since) = T::Randomness::random(&subject);
+                    ensure!(known_since > last_block, Error::<T>::DrawNotReady);
+                    let index = u32::decode(&mut seed.as_ref()).unwrap_or_default() as usize % eligible.len();
+                    Ok(Some((eligible[index].clone(), Zero::zero())))
+                },
+                // A winning bidder who then skipped the round loses the pot to the fixed order
+                PayoutOrder::Bidding => Ok(leading_bid
+                    .filter(|(bidder, _)| eligible.contains(bidder))
+                    .or_else(|| Some((eligible[0].clone(), Zero::zero())))),
+            }
+        }
+
+        /// Discard signatures and rejections collected under a previous signer set
+        fn refresh_signatures(withdrawal: &mut WithdrawalRequest<T>, signer_set: &SignerSet<T>) {
+            let open = matches!(withdrawal.status, WithdrawalStatus::Pending | WithdrawalStatus::Approved);
+            if !open || withdrawal.signer_set_version == signer_set.version {
+                return;
+            }
             
-            ensure!(
-                share > Zero::zero(),
-                Error::<T>::NoReturnsToDistribute
-            );
+            withdrawal.signatures = BoundedVec::new();
+            withdrawal.rejections = BoundedVec::new();
+            withdrawal.signer_set_version = signer_set.version;
+            withdrawal.status = WithdrawalStatus::Pending;
+            Self::deposit_event(Event::WithdrawalSignaturesReset {
+                club_id: withdrawal.club_id,
+                withdrawal_id: withdrawal.id,
+                version: signer_set.version,
+            });
+        }
+
+        /// Set the recorded treasury balance of a club to the free balance of
+        /// its treasury account
+        ///
+        /// Must be called by anything that moves funds of the treasury account
+        /// outside this pallet.
+        pub fn reconcile(club_id: ClubId) {
+            let recorded = Self::treasury_balance(club_id);
+            let actual = T::Currency::free_balance(&Self::treasury_account_id(club_id));
+            if recorded != actual {
+                TreasuryBalances::<T>::insert(club_id, actual);
+                Self::deposit_event(Event::TreasuryReconciled {
+                    club_id,
+                    recorded,
+                    actual,
+                });
+            }
+        }
+    }
+
+    /// Access to club treasuries for use by other pallets
+    pub trait ClubTreasury<AccountId> {
+        type Balance;
+
+        /// Account holding the club's treasury funds
+        fn account_of(club_id: ClubId) -> AccountId;
+        /// Treasury balance of the club that is free to spend
+        fn balance_of(club_id: ClubId) -> Self::Balance;
+        /// Pay `amount` out of the club treasury to `dest`, subject to the
+        /// club's spending limits
+        fn withdraw_to(club_id: ClubId, dest: &AccountId, amount: Self::Balance) -> DispatchResult;
+        /// Move `amount` from `source` into the club treasury
+        fn deposit_from(club_id: ClubId, source: &AccountId, amount: Self::Balance) -> DispatchResult;
+    }
+
+    impl<T: Config> ClubTreasury<T::AccountId> for Pallet<T> {
+        type Balance = BalanceOf<T>;
+
+        fn account_of(club_id: ClubId) -> T::AccountId {
+            Self::treasury_account_id(club_id)
+        }
+
+        fn balance_of(club_id: ClubId) -> BalanceOf<T> {
+            Self::available_balance(club_id)
+        }
+
+        fn withdraw_to(club_id: ClubId, dest: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(Self::available_balance(club_id) >= amount, Error::<T>::InsufficientBalance);
+            Self::charge_spending(club_id, amount)?;
             
-            // Transfer share from treasury
             T::Currency::transfer(
                 &Self::treasury_account_id(club_id),
-                &claimant,
-                share,
+                dest,
+                amount,
                 ExistenceRequirement::AllowDeath,
             )?;
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_sub(amount));
             
-            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_sub(share));
+            Self::deposit_event(Event::FundsWithdrawn {
+                club_id,
+                dest: dest.clone(),
+                amount,
+            });
             
             Ok(())
         }
-    }
-
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            // Auto-close expired cycles
-            // This would iterate through active cycles and close expired ones
-            // For MVP, we'll rely on manual closing
-            Weight::zero()
-        }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Generate treasury account ID for a club
-        pub fn treasury_account_id(club_id: ClubId) -> T::AccountId {
-            use sp_runtime::traits::AccountIdConversion;
-            use sp_runtime::ModuleId;
+        fn deposit_from(club_id: ClubId, source: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            T::Currency::transfer(
+                source,
+                &Self::treasury_account_id(club_id),
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            TreasuryBalances::<T>::mutate(club_id, |balance| *balance = balance.saturating_add(amount));
+            
+            Self::deposit_event(Event::FundsDeposited {
+                club_id,
+                account: source.clone(),
+                amount,
+            });
             
-            // Create a deterministic account from module ID and club ID
-            let module_id = ModuleId(*b"ikubtrsy");
-            module_id.into_account_truncating(&club_id.encode())
+            Ok(())
         }
     }
 }
-
@@ -0,0 +1,265 @@
+//! Storage migrations for the treasury pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod v1 {
+    use super::*;
+
+    /// Withdrawal request layout without a signer set version
+    #[derive(Decode)]
+    pub struct OldWithdrawalRequest<T: Config> {
+        pub id: WithdrawalId,
+        pub club_id: ClubId,
+        pub recipient: T::AccountId,
+        pub amount: BalanceOf<T>,
+        pub unlock_at: BlockNumberFor<T>,
+        pub status: WithdrawalStatus,
+        pub signatures: BoundedVec<T::AccountId, T::MaxSigners>,
+        pub created_at: BlockNumberFor<T>,
+    }
+
+    /// Tags withdrawal requests with signer set version 0 and their requester
+    ///
+    /// Signatures on requests that were not yet executed came from arbitrary
+    /// accounts, so they are dropped and the requests return to pending. The
+    /// first signature of a request was its requester's.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            WithdrawalRequests::<T>::translate::<OldWithdrawalRequest<T>, _>(|_, _, old| {
+                translated += 1;
+                let unsettled = matches!(old.status, WithdrawalStatus::Pending | WithdrawalStatus::Approved);
+                let requester = old.signatures.first().cloned().unwrap_or_else(|| old.recipient.clone());
+                Some(WithdrawalRequest {
+                    id: old.id,
+                    club_id: old.club_id,
+                    requester,
+                    recipient: old.recipient,
+                    amount: old.amount,
+                    unlock_at: old.unlock_at,
+                    status: if unsettled { WithdrawalStatus::Pending } else { old.status },
+                    signatures: if unsettled { BoundedVec::new() } else { old.signatures },
+                    rejections: BoundedVec::new(),
+                    signer_set_version: 0,
+                    created_at: old.created_at,
+                })
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Resets recorded treasury balances that drifted from their treasury
+    /// accounts, e.g. after cross-chain sends that bypassed the ledger
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let clubs: Vec<ClubId> = TreasuryBalances::<T>::iter_keys().collect();
+            for club_id in clubs.iter() {
+                Pallet::<T>::reconcile(*club_id);
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            let count = clubs.len() as u64;
+            T::DbWeight::get().reads_writes(2 * count + 1, count + 1)
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+    use sp_runtime::traits::{One, Saturating};
+
+    /// Earmarks the amounts of withdrawal requests that are still open and
+    /// schedules their expiry
+    ///
+    /// Open requests expire `WithdrawalExpiry` blocks after the upgrade, those
+    /// of a club beyond `MaxExpiringWithdrawals` in the following blocks.
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 3 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::WithdrawalExpiry::get());
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+            for (club_id, withdrawal_id, withdrawal) in WithdrawalRequests::<T>::iter() {
+                reads += 1;
+                if matches!(withdrawal.status, WithdrawalStatus::Pending | WithdrawalStatus::Approved) {
+                    writes += 2;
+                    Earmarked::<T>::mutate(club_id, |earmarked| {
+                        *earmarked = earmarked.saturating_add(withdrawal.amount)
+                    });
+                    let mut at = expires_at;
+                    while WithdrawalExpiries::<T>::try_mutate(at, club_id, |expiring| expiring.try_push(withdrawal_id))
+                        .is_err()
+                    {
+                        reads += 1;
+                        at = at.saturating_add(One::one());
+                    }
+                    reads += 1;
+                }
+            }
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+}
+
+pub mod v4 {
+    use super::*;
+    use sp_runtime::traits::{One, Saturating};
+
+    /// Schedules the automatic closing of cycles that are still open
+    pub struct MigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 4 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+            for (club_id, cycle_id) in ActiveCycle::<T>::iter() {
+                reads += 2;
+                let cycle = match ContributionCycles::<T>::get(club_id, cycle_id) {
+                    Some(cycle) => cycle,
+                    None => continue,
+                };
+                // Overdue cycles close from the first block after the upgrade,
+                // `MaxClosingsPerBlock` at a time
+                let closes_at = cycle.end_block.max(now).saturating_add(One::one());
+                CycleClosings::<T>::insert(closes_at, club_id, cycle_id);
+                writes += 1;
+            }
+
+            StorageVersion::new(4).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+}
+
+pub mod v5 {
+    use super::*;
+    use sp_runtime::traits::Zero;
+
+    /// Contribution cycle layout without claim tracking
+    #[derive(Decode)]
+    pub struct OldContributionCycle<T: Config> {
+        pub id: ContributionCycleId,
+        pub club_id: ClubId,
+        pub start_block: BlockNumberFor<T>,
+        pub end_block: BlockNumberFor<T>,
+        pub total_contributions: BalanceOf<T>,
+        pub returns: BalanceOf<T>,
+        pub status: CycleStatus,
+        pub minimum_contribution: BalanceOf<T>,
+    }
+
+    /// Adds claim tracking to contribution cycles
+    ///
+    /// Claims made before the upgrade were not recorded, so distributed
+    /// cycles are closed to further claims as swept rather than reopened.
+    pub struct MigrateToV5<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 5 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            ContributionCycles::<T>::translate::<OldContributionCycle<T>, _>(|_, _, old| {
+                translated += 1;
+                let status = if old.status == CycleStatus::Distributed {
+                    CycleStatus::Swept
+                } else {
+                    old.status
+                };
+                Some(ContributionCycle {
+                    id: old.id,
+                    club_id: old.club_id,
+                    start_block: old.start_block,
+                    end_block: old.end_block,
+                    total_contributions: old.total_contributions,
+                    returns: old.returns,
+                    status,
+                    minimum_contribution: old.minimum_contribution,
+                    claimed_returns: Zero::zero(),
+                    claim_deadline: None,
+                })
+            });
+
+            StorageVersion::new(5).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+    }
+}
+
+pub mod v6 {
+    use super::*;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    /// Seeds the unit ledger with one unit for every unit contributed to a
+    /// cycle before the upgrade
+    ///
+    /// Treasury funds beyond what members contributed are issued as units
+    /// to the club's own treasury account, so that the contributors' units
+    /// are not worth the club's whole treasury.
+    pub struct MigrateToV6<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 6 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut seeded = 0u64;
+            for ((club_id, _, account), contribution) in Contributions::<T>::iter() {
+                seeded += 1;
+                Units::<T>::mutate(club_id, &account, |units| *units = units.saturating_add(contribution.amount));
+                TotalUnits::<T>::mutate(club_id, |total| *total = total.saturating_add(contribution.amount));
+            }
+
+            let clubs: Vec<ClubId> = TreasuryBalances::<T>::iter_keys().collect();
+            for club_id in clubs.iter() {
+                let equity = Pallet::<T>::net_asset_value(*club_id).saturating_sub(TotalUnits::<T>::get(club_id));
+                if !equity.is_zero() {
+                    Pallet::<T>::mint_club_equity(*club_id, equity);
+                }
+            }
+
+            StorageVersion::new(6).put::<Pallet<T>>();
+            let count = clubs.len() as u64;
+            T::DbWeight::get().reads_writes(3 * seeded + 5 * count + 1, 2 * seeded + 2 * count + 1)
+        }
+    }
+}
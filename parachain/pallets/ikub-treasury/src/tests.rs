@@ -0,0 +1,1193 @@
+use crate::{mock::*, ClubTreasury, CycleStatus, Error, Event, MemberEquity, PayoutOrder, RoscaStatus, ShareMode, WithdrawalStatus};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, ExistenceRequirement, Get, Hooks},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn fund_club_with_signers(amount: Balance) {
+    assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 2));
+    assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(ALICE), CLUB, amount));
+}
+
+#[test]
+fn request_earmarks_funds() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 60, 0));
+
+        assert_eq!(IkubTreasury::earmarked(CLUB), 60);
+        assert_eq!(IkubTreasury::available_balance(CLUB), 40);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 100);
+    });
+}
+
+#[test]
+fn concurrent_requests_cannot_overdraw() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 60, 0));
+        assert_noop!(
+            IkubTreasury::request_withdrawal(RuntimeOrigin::signed(BOB), CLUB, RECIPIENT, 60, 0),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(BOB), CLUB, RECIPIENT, 40, 0));
+
+        assert_eq!(IkubTreasury::available_balance(CLUB), 0);
+
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 1));
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, 0));
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, 1));
+
+        assert_eq!(Balances::free_balance(RECIPIENT), 100);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 0);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn execution_releases_earmark() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 30, 0));
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_eq!(IkubTreasury::available_balance(CLUB), 70);
+    });
+}
+
+#[test]
+fn cancellation_releases_earmark() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 100, 0));
+        assert_ok!(IkubTreasury::cancel_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(BOB), CLUB, RECIPIENT, 100, 0));
+    });
+}
+
+#[test]
+fn rejection_releases_earmark() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 100, 0));
+        assert_ok!(IkubTreasury::reject_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_eq!(IkubTreasury::earmarked(CLUB), 100);
+        assert_ok!(IkubTreasury::reject_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, 0));
+
+        assert_eq!(
+            IkubTreasury::withdrawal_requests(CLUB, 0).unwrap().status,
+            WithdrawalStatus::Rejected
+        );
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn expiry_releases_earmark() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 100, 0));
+        IkubTreasury::on_initialize(51);
+
+        assert_eq!(
+            IkubTreasury::withdrawal_requests(CLUB, 0).unwrap().status,
+            WithdrawalStatus::Cancelled
+        );
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn only_signers_request_and_approve() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        assert_noop!(
+            IkubTreasury::request_withdrawal(RuntimeOrigin::signed(RECIPIENT), CLUB, RECIPIENT, 30, 0),
+            Error::<Test>::NotSigner
+        );
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 30, 0));
+        assert_noop!(
+            IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(RECIPIENT), CLUB, 0),
+            Error::<Test>::NotSigner
+        );
+        assert_noop!(
+            IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0),
+            Error::<Test>::AlreadySigned
+        );
+    });
+}
+
+#[test]
+fn changing_the_signer_set_invalidates_signatures() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 30, 0));
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0));
+
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, CHARLIE, RECIPIENT], 2));
+        assert_noop!(
+            IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0),
+            Error::<Test>::SignerSetChanged
+        );
+        assert_noop!(
+            IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0),
+            Error::<Test>::NotSigner
+        );
+
+        // Signatures are collected again from the new signers
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, 0));
+        System::assert_has_event(
+            Event::<Test>::WithdrawalSignaturesReset { club_id: CLUB, withdrawal_id: 0, version: 2 }.into(),
+        );
+        assert_eq!(
+            IkubTreasury::withdrawal_requests(CLUB, 0).unwrap().status,
+            WithdrawalStatus::Pending
+        );
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_eq!(Balances::free_balance(RECIPIENT), 30);
+    });
+}
+
+#[test]
+fn cross_chain_sends_keep_the_recorded_balance_backed() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+
+        // Cross-chain sends pay out through the treasury and are recorded
+        assert_ok!(<IkubTreasury as ClubTreasury<AccountId>>::withdraw_to(CLUB, &RECIPIENT, 40));
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 60);
+        assert_ok!(IkubTreasury::do_try_state());
+
+        // Funds moved out of the treasury account elsewhere are not
+        assert_ok!(<Balances as Currency<AccountId>>::transfer(
+            &IkubTreasury::treasury_account_id(CLUB),
+            &RECIPIENT,
+            20,
+            ExistenceRequirement::AllowDeath,
+        ));
+        assert!(IkubTreasury::do_try_state().is_err());
+
+        assert_ok!(IkubTreasury::reconcile_treasury(RuntimeOrigin::signed(BOB), CLUB));
+        System::assert_last_event(Event::<Test>::TreasuryReconciled { club_id: CLUB, recorded: 60, actual: 40 }.into());
+        assert_eq!(IkubTreasury::available_balance(CLUB), 40);
+        assert_ok!(IkubTreasury::do_try_state());
+    });
+}
+
+fn approved_withdrawal(amount: Balance) -> u64 {
+    let withdrawal_id = IkubTreasury::withdrawal_count(CLUB);
+    assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, amount, 0));
+    assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, withdrawal_id));
+    withdrawal_id
+}
+
+#[test]
+fn withdrawal_above_per_withdrawal_max_is_refused() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(500);
+        assert_ok!(IkubTreasury::set_spending_limit(RuntimeOrigin::root(), 50, 100, 10));
+
+        let withdrawal_id = approved_withdrawal(60);
+        assert_noop!(
+            IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, withdrawal_id),
+            Error::<Test>::SpendingLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn period_cap_resets_after_the_period() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(500);
+        assert_ok!(IkubTreasury::set_spending_limit(RuntimeOrigin::root(), 50, 100, 10));
+
+        let first = approved_withdrawal(50);
+        let second = approved_withdrawal(50);
+        let third = approved_withdrawal(50);
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, first));
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, second));
+        assert_noop!(
+            IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, third),
+            Error::<Test>::SpendingLimitExceeded
+        );
+
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, third));
+        assert_eq!(IkubTreasury::spending_window(CLUB), (11, 50));
+    });
+}
+
+#[test]
+fn governance_override_covers_spending_beyond_the_limits() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(500);
+        assert_ok!(IkubTreasury::set_spending_limit(RuntimeOrigin::root(), 50, 100, 10));
+        assert_ok!(IkubTreasury::grant_spending_override(RuntimeOrigin::root(), 200));
+
+        let withdrawal_id = approved_withdrawal(200);
+        assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, withdrawal_id));
+
+        assert_eq!(IkubTreasury::spending_override(CLUB), 0);
+        assert_eq!(Balances::free_balance(RECIPIENT), 200);
+    });
+}
+
+fn open_rosca(order: PayoutOrder) -> u64 {
+    let rosca_id = IkubTreasury::rosca_count(CLUB);
+    assert_ok!(IkubTreasury::open_rosca(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 100, 10, order));
+    rosca_id
+}
+
+fn contribute_all(rosca_id: u64, participants: &[AccountId]) {
+    for who in participants {
+        assert_ok!(IkubTreasury::contribute_to_round(RuntimeOrigin::signed(*who), CLUB, rosca_id));
+    }
+}
+
+#[test]
+fn fixed_rosca_pays_each_participant_once_in_order() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Fixed);
+
+        for (round, recipient) in [ALICE, BOB, CHARLIE].into_iter().enumerate() {
+            contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+            assert_eq!(IkubTreasury::available_balance(CLUB), 0);
+            System::set_block_number(System::block_number() + 10);
+            assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id));
+            assert_eq!(IkubTreasury::rosca_recipients((CLUB, rosca_id, recipient)), Some(round as u32));
+        }
+
+        assert_eq!(IkubTreasury::roscas(CLUB, rosca_id).unwrap().status, RoscaStatus::Completed);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 0);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn defaulting_participant_is_skipped() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Fixed);
+
+        contribute_all(rosca_id, &[BOB, CHARLIE]);
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(BOB), CLUB, rosca_id));
+
+        assert_eq!(IkubTreasury::rosca_recipients((CLUB, rosca_id, ALICE)), None);
+        assert_eq!(IkubTreasury::rosca_recipients((CLUB, rosca_id, BOB)), Some(0));
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+    });
+}
+
+#[test]
+fn round_cannot_be_settled_early() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Lottery);
+
+        contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+        assert_noop!(
+            IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id),
+            Error::<Test>::RoundNotOver
+        );
+        assert_noop!(
+            IkubTreasury::contribute_to_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id),
+            Error::<Test>::AlreadyContributed
+        );
+    });
+}
+
+#[test]
+fn lottery_waits_for_randomness_revealed_after_the_round() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Lottery);
+        contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+
+        // Block 11 draws from randomness fixed by the round's last block
+        System::set_block_number(11);
+        System::set_parent_hash(H256::repeat_byte(1));
+        assert_noop!(
+            IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id),
+            Error::<Test>::DrawNotReady
+        );
+
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id));
+    });
+}
+
+#[test]
+fn lottery_recipient_is_drawn_from_the_randomness() {
+    let draw_with = |parent_hash: H256| {
+        new_test_ext().execute_with(|| {
+            let rosca_id = open_rosca(PayoutOrder::Lottery);
+            contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+            System::set_block_number(12);
+            System::set_parent_hash(parent_hash);
+            assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id));
+            [ALICE, BOB, CHARLIE]
+                .into_iter()
+                .find(|who| IkubTreasury::rosca_recipients((CLUB, rosca_id, *who)).is_some())
+                .unwrap()
+        })
+    };
+
+    // The same randomness always draws the same recipient, while the draw
+    // changes with the randomness
+    let recipients: Vec<AccountId> = (1..=8).map(|byte| draw_with(H256::repeat_byte(byte))).collect();
+    assert_eq!(draw_with(H256::repeat_byte(1)), recipients[0]);
+    assert!(recipients.iter().any(|who| *who != recipients[0]));
+}
+
+#[test]
+fn rosca_participants_must_be_members() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            IkubTreasury::open_rosca(RuntimeOrigin::root(), vec![ALICE, RECIPIENT], 100, 10, PayoutOrder::Fixed),
+            Error::<Test>::NotClubMember
+        );
+    });
+}
+
+#[test]
+fn highest_bidder_receives_pot_minus_discount() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Bidding);
+
+        contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+        assert_ok!(IkubTreasury::bid_for_round(RuntimeOrigin::signed(CHARLIE), CLUB, rosca_id, 20));
+        assert_noop!(
+            IkubTreasury::bid_for_round(RuntimeOrigin::signed(BOB), CLUB, rosca_id, 20),
+            Error::<Test>::InvalidBid
+        );
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id));
+
+        assert_eq!(Balances::free_balance(CHARLIE), 1_180);
+        assert_eq!(Balances::free_balance(ALICE), 910);
+        assert_eq!(Balances::free_balance(BOB), 910);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 0);
+    });
+}
+
+#[test]
+fn past_winner_cannot_bid_again() {
+    new_test_ext().execute_with(|| {
+        let rosca_id = open_rosca(PayoutOrder::Bidding);
+
+        contribute_all(rosca_id, &[ALICE, BOB, CHARLIE]);
+        assert_ok!(IkubTreasury::bid_for_round(RuntimeOrigin::signed(CHARLIE), CLUB, rosca_id, 20));
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(ALICE), CLUB, rosca_id));
+
+        assert_noop!(
+            IkubTreasury::bid_for_round(RuntimeOrigin::signed(CHARLIE), CLUB, rosca_id, 30),
+            Error::<Test>::AlreadyReceived
+        );
+    });
+}
+
+#[test]
+fn sealed_auction_pays_highest_revealed_bid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::open_auction_rosca(
+            RuntimeOrigin::root(),
+            vec![ALICE, BOB, CHARLIE],
+            100,
+            10,
+            Some(5),
+        ));
+
+        contribute_all(0, &[ALICE, BOB, CHARLIE]);
+        assert_noop!(
+            IkubTreasury::bid_for_round(RuntimeOrigin::signed(ALICE), CLUB, 0, 10),
+            Error::<Test>::SealedAuction
+        );
+        let alice_bid = BlakeTwo256::hash_of(&(ALICE, 0u64, 0u32, 10u128, [1u8; 32]));
+        let bob_bid = BlakeTwo256::hash_of(&(BOB, 0u64, 0u32, 30u128, [2u8; 32]));
+        assert_ok!(IkubTreasury::commit_bid(RuntimeOrigin::signed(ALICE), CLUB, 0, alice_bid));
+        assert_ok!(IkubTreasury::commit_bid(RuntimeOrigin::signed(BOB), CLUB, 0, bob_bid));
+        assert_noop!(
+            IkubTreasury::reveal_bid(RuntimeOrigin::signed(BOB), CLUB, 0, 30, [2u8; 32]),
+            Error::<Test>::NotRevealPeriod
+        );
+
+        System::set_block_number(6);
+        assert_noop!(
+            IkubTreasury::reveal_bid(RuntimeOrigin::signed(ALICE), CLUB, 0, 20, [1u8; 32]),
+            Error::<Test>::InvalidReveal
+        );
+        assert_ok!(IkubTreasury::reveal_bid(RuntimeOrigin::signed(BOB), CLUB, 0, 30, [2u8; 32]));
+        assert_noop!(
+            IkubTreasury::reveal_bid(RuntimeOrigin::signed(ALICE), CLUB, 0, 10, [1u8; 32]),
+            Error::<Test>::InvalidBid
+        );
+
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::settle_round(RuntimeOrigin::signed(CHARLIE), CLUB, 0));
+
+        assert_eq!(IkubTreasury::rosca_recipients((CLUB, 0, BOB)), Some(0));
+        assert_eq!(Balances::free_balance(BOB), 1_170);
+        assert_eq!(Balances::free_balance(ALICE), 915);
+        assert_eq!(Balances::free_balance(CHARLIE), 915);
+    });
+}
+
+fn collection_weight(mandates: u64) -> Weight {
+    let db = <Test as frame_system::Config>::DbWeight::get();
+    db.reads_writes(2, 2) + db.reads_writes(2, 2) + db.reads_writes(10, 9) * mandates
+}
+
+#[test]
+fn mandates_are_collected_when_a_cycle_opens() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(ALICE), CLUB, 20, 40));
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(BOB), CLUB, 30, 100));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, None, None));
+
+        IkubTreasury::on_idle(1, Weight::MAX);
+
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, ALICE)).unwrap().amount, 20);
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, BOB)).unwrap().amount, 30);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().total_contributions, 50);
+        assert_eq!(IkubTreasury::mandates(CLUB, ALICE).unwrap().collected, 20);
+        assert_eq!(IkubTreasury::pending_collections(CLUB, 0), None);
+    });
+}
+
+#[test]
+fn collection_respects_the_weight_budget() {
+    new_test_ext().execute_with(|| {
+        for who in [ALICE, BOB, CHARLIE] {
+            assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(who), CLUB, 20, 100));
+        }
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, None, None));
+
+        assert_eq!(IkubTreasury::on_idle(1, collection_weight(1)), collection_weight(1));
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().total_contributions, 20);
+        assert_eq!(IkubTreasury::pending_collections(CLUB, 0), Some(()));
+
+        IkubTreasury::on_idle(1, Weight::MAX);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().total_contributions, 60);
+        assert_eq!(IkubTreasury::pending_collections(CLUB, 0), None);
+    });
+}
+
+#[test]
+fn mandates_left_uncollected_are_recorded_as_missed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(ALICE), CLUB, 20, 100));
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(BOB), CLUB, 20, 100));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(5), None));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 15));
+
+        System::set_block_number(7);
+        IkubTreasury::on_idle(7, Weight::MAX);
+
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, ALICE)), None);
+        assert_eq!(IkubTreasury::missed_contributions((CLUB, 0, ALICE)), 20);
+        assert_eq!(IkubTreasury::missed_contributions((CLUB, 0, BOB)), 5);
+        assert_eq!(IkubTreasury::pending_collections(CLUB, 0), None);
+    });
+}
+
+#[test]
+fn mandates_require_membership() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            IkubTreasury::set_mandate(RuntimeOrigin::signed(RECIPIENT), CLUB, 20, 100),
+            Error::<Test>::NotClubMember
+        );
+    });
+}
+
+#[test]
+fn failed_collection_is_recorded_as_missed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(ALICE), CLUB, 5_000, 10_000));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, None, None));
+
+        IkubTreasury::on_idle(1, Weight::MAX);
+
+        assert_eq!(IkubTreasury::missed_contributions((CLUB, 0, ALICE)), 5_000);
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, ALICE)), None);
+        assert_eq!(IkubTreasury::mandates(CLUB, ALICE).unwrap().collected, 0);
+    });
+}
+
+#[test]
+fn revoked_mandate_is_not_collected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(ALICE), CLUB, 20, 40));
+        assert_ok!(IkubTreasury::revoke_mandate(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_noop!(
+            IkubTreasury::revoke_mandate(RuntimeOrigin::signed(ALICE), CLUB),
+            Error::<Test>::MandateNotFound
+        );
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, None, None));
+
+        IkubTreasury::on_idle(1, Weight::MAX);
+
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, ALICE)), None);
+    });
+}
+
+fn club_with_payment_terms() {
+    assert_ok!(IkubTreasury::set_payment_terms(RuntimeOrigin::root(), 5, 7));
+}
+
+#[test]
+fn contribution_in_grace_period_pays_late_fee() {
+    new_test_ext().execute_with(|| {
+        club_with_payment_terms();
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+
+        System::set_block_number(14);
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 50));
+        assert_eq!(Balances::free_balance(BOB), 943);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 57);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().total_contributions, 50);
+        assert_noop!(
+            IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB),
+            Error::<Test>::CycleNotClosed
+        );
+
+        System::set_block_number(17);
+        assert_noop!(
+            IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 50),
+            Error::<Test>::CycleNotOpen
+        );
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+    });
+}
+
+#[test]
+fn missed_contributions_are_reported_once() {
+    new_test_ext().execute_with(|| {
+        club_with_payment_terms();
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), Some(40)));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 40));
+
+        assert_noop!(
+            IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, 0, BOB),
+            Error::<Test>::GracePeriodNotOver
+        );
+        System::set_block_number(17);
+        assert_noop!(
+            IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(BOB), CLUB, 0, ALICE),
+            Error::<Test>::ContributionNotMissed
+        );
+        assert_noop!(
+            IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(BOB), CLUB, 0, RECIPIENT),
+            Error::<Test>::NotObligated
+        );
+        assert_ok!(IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, 0, BOB));
+        assert_noop!(
+            IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, 0, BOB),
+            Error::<Test>::AlreadyReported
+        );
+
+        assert_eq!(IkubTreasury::missed_contributions((CLUB, 0, BOB)), 40);
+        assert_eq!(IkubTreasury::missed_payment_count(CLUB, BOB), 1);
+    });
+}
+
+#[test]
+fn repeated_misses_suspend_the_member() {
+    new_test_ext().execute_with(|| {
+        club_with_payment_terms();
+
+        for cycle_id in 0..2 {
+            assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+            System::set_block_number(System::block_number() + 16);
+            assert_ok!(IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, cycle_id, BOB));
+            assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        }
+
+        assert!(IkubMembers::suspended(CLUB, BOB).is_some());
+        assert!(!<IkubMembers as pallet_ikub_members::ClubMembership<_, _>>::is_member(CLUB, &BOB));
+    });
+}
+
+#[test]
+fn failed_collections_count_towards_suspension() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_mandate(RuntimeOrigin::signed(BOB), CLUB, 5_000, 10_000));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), Some(40)));
+        IkubTreasury::on_idle(1, Weight::MAX);
+
+        assert_eq!(IkubTreasury::missed_payment_count(CLUB, BOB), 1);
+        assert_eq!(IkubMembers::members(CLUB, BOB).unwrap().reputation, 90);
+
+        System::set_block_number(12);
+        assert_noop!(
+            IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, 0, BOB),
+            Error::<Test>::AlreadyReported
+        );
+        assert_eq!(IkubTreasury::missed_contributions((CLUB, 0, BOB)), 5_000);
+    });
+}
+
+#[test]
+fn suspended_members_stay_suspended_and_cannot_contribute() {
+    new_test_ext().execute_with(|| {
+        club_with_payment_terms();
+
+        for cycle_id in 0..2 {
+            assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+            System::set_block_number(System::block_number() + 16);
+            assert_ok!(IkubTreasury::report_missed_contribution(RuntimeOrigin::signed(ALICE), CLUB, cycle_id, BOB));
+            assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        }
+        assert_eq!(IkubMembers::members(CLUB, BOB).unwrap().reputation, 80);
+
+        assert_ok!(IkubMembers::leave_club(RuntimeOrigin::signed(BOB), CLUB));
+        assert_ok!(IkubMembers::join_club(RuntimeOrigin::signed(BOB), CLUB));
+        assert!(IkubMembers::suspended(CLUB, BOB).is_some());
+
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        assert_noop!(
+            IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 20),
+            Error::<Test>::NotClubMember
+        );
+        assert_noop!(
+            IkubTreasury::set_mandate(RuntimeOrigin::signed(BOB), CLUB, 20, 100),
+            Error::<Test>::NotClubMember
+        );
+    });
+}
+
+#[test]
+fn cycles_close_on_their_own_after_the_grace_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_payment_terms(RuntimeOrigin::root(), 5, 0));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+
+        IkubTreasury::on_initialize(16);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().status, CycleStatus::Open);
+
+        IkubTreasury::on_initialize(17);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().status, CycleStatus::Closed);
+        assert_eq!(IkubTreasury::active_cycle(CLUB), None);
+    });
+}
+
+#[test]
+fn only_members_open_cycles() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(RECIPIENT), CLUB, Some(10), None),
+            Error::<Test>::NotClubMember
+        );
+    });
+}
+
+#[test]
+fn recurring_cycles_roll_over_with_the_same_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_recurring_cycles(RuntimeOrigin::root(), true));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), Some(25)));
+
+        System::set_block_number(12);
+        IkubTreasury::on_initialize(12);
+
+        let next = IkubTreasury::contribution_cycles(CLUB, 1).unwrap();
+        assert_eq!(IkubTreasury::active_cycle(CLUB), Some(1));
+        assert_eq!((next.start_block, next.end_block), (12, 22));
+        assert_eq!(next.minimum_contribution, 25);
+    });
+}
+
+#[test]
+fn closings_beyond_the_block_limit_spill_over() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubMembers::create_club(RuntimeOrigin::signed(BOB), b"club".to_vec(), Vec::new()));
+        assert_ok!(IkubMembers::create_club(RuntimeOrigin::signed(CHARLIE), b"club".to_vec(), Vec::new()));
+        let clubs = [(ALICE, CLUB), (BOB, CLUB + 1), (CHARLIE, CLUB + 2)];
+        for (member, club_id) in clubs {
+            assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(member), club_id, Some(10), None));
+        }
+        let open_cycles = || clubs.iter().filter(|(_, club_id)| IkubTreasury::active_cycle(club_id).is_some()).count();
+
+        // Two cycles close at a time
+        System::set_block_number(12);
+        IkubTreasury::on_initialize(12);
+        assert_eq!(open_cycles(), 1);
+
+        System::set_block_number(13);
+        IkubTreasury::on_initialize(13);
+        assert_eq!(open_cycles(), 0);
+    });
+}
+
+#[test]
+fn manually_closed_cycle_is_skipped_by_the_hook() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_recurring_cycles(RuntimeOrigin::root(), true));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_eq!(IkubTreasury::active_cycle(CLUB), Some(1));
+
+        IkubTreasury::on_initialize(12);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 1).unwrap().status, CycleStatus::Open);
+        assert_eq!(IkubTreasury::cycle_count(CLUB), 2);
+    });
+}
+
+fn distributed_cycle(returns: Balance) {
+    assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, returns));
+    assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+    assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+    assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 300));
+    System::set_block_number(12);
+    assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+    assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, returns));
+}
+
+#[test]
+fn returns_cannot_be_claimed_twice() {
+    new_test_ext().execute_with(|| {
+        distributed_cycle(80);
+
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_eq!(Balances::free_balance(ALICE), 920);
+        assert_noop!(
+            IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0),
+            Error::<Test>::AlreadyClaimed
+        );
+
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_noop!(
+            IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0),
+            Error::<Test>::AlreadyClaimed
+        );
+
+        assert_eq!(IkubTreasury::claimed_returns((CLUB, 0, ALICE)), Some(20));
+        assert_eq!(IkubTreasury::claimed_returns((CLUB, 0, BOB)), Some(60));
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().claimed_returns, 80);
+        assert_eq!(IkubTreasury::treasury_balance(CLUB), 400);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn distributed_returns_are_held_for_claimants() {
+    new_test_ext().execute_with(|| {
+        distributed_cycle(80);
+
+        assert_eq!(IkubTreasury::available_balance(CLUB), 400);
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB], 2));
+        assert_noop!(
+            IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 401, 0),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn unclaimed_returns_are_swept_after_the_deadline() {
+    new_test_ext().execute_with(|| {
+        distributed_cycle(80);
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_noop!(
+            IkubTreasury::sweep_unclaimed_returns(RuntimeOrigin::signed(ALICE), CLUB, 0),
+            Error::<Test>::ClaimPeriodNotOver
+        );
+
+        System::set_block_number(33);
+        assert_noop!(
+            IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0),
+            Error::<Test>::ClaimPeriodOver
+        );
+        assert_ok!(IkubTreasury::sweep_unclaimed_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().status, CycleStatus::Swept);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_eq!(IkubTreasury::available_balance(CLUB), 460);
+        assert_noop!(
+            IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0),
+            Error::<Test>::CycleNotClosed
+        );
+    });
+}
+
+#[test]
+fn distribution_needs_governance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 80));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+
+        assert_noop!(
+            IkubTreasury::distribute_returns(RuntimeOrigin::signed(ALICE), 0, 80),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn distribution_fails_beyond_unallocated_funds() {
+    new_test_ext().execute_with(|| {
+        fund_club_with_signers(100);
+        assert_ok!(IkubTreasury::request_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, RECIPIENT, 60, 0));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+
+        assert_noop!(
+            IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 41),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 40));
+        assert_eq!(IkubTreasury::available_balance(CLUB), 0);
+    });
+}
+
+#[test]
+fn deposited_returns_fund_the_distribution() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+        assert_ok!(IkubTreasury::fund_returns(RuntimeOrigin::signed(CHARLIE), CLUB, 0, 50));
+        assert_eq!(IkubTreasury::available_balance(CLUB), 100);
+
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 30));
+
+        // Deposited funds beyond the returns are freed
+        assert_eq!(IkubTreasury::earmarked(CLUB), 30);
+        assert_eq!(IkubTreasury::available_balance(CLUB), 120);
+        assert_eq!(IkubTreasury::return_funds(CLUB, 0), 0);
+
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_eq!(Balances::free_balance(ALICE), 930);
+    });
+}
+
+#[test]
+fn pushed_returns_are_paid_across_blocks() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 100));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        for contributor in [ALICE, BOB, CHARLIE] {
+            assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(contributor), CLUB, 100));
+        }
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 100));
+        assert_ok!(IkubTreasury::push_returns(RuntimeOrigin::root(), 0));
+
+        // The budget covers two contributors per block
+        IkubTreasury::on_initialize(13);
+        let cycle = IkubTreasury::contribution_cycles(CLUB, 0).unwrap();
+        assert_eq!(cycle.status, CycleStatus::Distributed);
+        assert_eq!(cycle.claimed_returns, 66);
+        assert_eq!(IkubTreasury::pending_payouts(CLUB, 0), Some(()));
+
+        IkubTreasury::on_initialize(14);
+        let cycle = IkubTreasury::contribution_cycles(CLUB, 0).unwrap();
+        assert_eq!(cycle.status, CycleStatus::Swept);
+        assert_eq!(cycle.claimed_returns, 99);
+        assert_eq!(IkubTreasury::pending_payouts(CLUB, 0), None);
+        assert_eq!(Balances::free_balance(ALICE), 933);
+        assert_eq!(Balances::free_balance(BOB), 933);
+        assert_eq!(Balances::free_balance(CHARLIE), 833);
+
+        // Rounding dust goes back to the treasury
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_eq!(IkubTreasury::available_balance(CLUB), 301);
+        System::assert_last_event(
+            Event::<Test>::ReturnsPayoutCompleted { club_id: CLUB, cycle_id: 0, unpaid_accounts: 0, dust: 1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn unpaid_pushed_returns_stay_claimable() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        for contributor in [ALICE, BOB, CHARLIE] {
+            assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(contributor), CLUB, 100));
+        }
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 99));
+        assert_ok!(IkubTreasury::push_returns(RuntimeOrigin::root(), 0));
+
+        // The treasury account holds enough for two shares only
+        let treasury = IkubTreasury::treasury_account_id(CLUB);
+        Balances::make_free_balance_be(&treasury, 70);
+        IkubTreasury::on_initialize(13);
+        IkubTreasury::on_initialize(14);
+
+        let cycle = IkubTreasury::contribution_cycles(CLUB, 0).unwrap();
+        assert_eq!(cycle.status, CycleStatus::Distributed);
+        assert_eq!(cycle.claimed_returns, 66);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 33);
+        assert_eq!(IkubTreasury::pending_payouts(CLUB, 0), None);
+        System::assert_last_event(
+            Event::<Test>::ReturnsPayoutCompleted { club_id: CLUB, cycle_id: 0, unpaid_accounts: 1, dust: 0 }.into(),
+        );
+
+        let unpaid = [ALICE, BOB, CHARLIE]
+            .into_iter()
+            .find(|who| IkubTreasury::claimed_returns((CLUB, 0, *who)).is_none())
+            .unwrap();
+        Balances::make_free_balance_be(&treasury, 400);
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(unpaid), CLUB, 0));
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+    });
+}
+
+#[test]
+fn pushed_returns_skip_claimed_contributors() {
+    new_test_ext().execute_with(|| {
+        distributed_cycle(80);
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_ok!(IkubTreasury::push_returns(RuntimeOrigin::root(), 0));
+        assert_noop!(
+            IkubTreasury::push_returns(RuntimeOrigin::root(), 0),
+            Error::<Test>::PayoutAlreadyQueued
+        );
+
+        IkubTreasury::on_initialize(13);
+        assert_eq!(Balances::free_balance(ALICE), 920);
+        assert_eq!(Balances::free_balance(BOB), 760);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().status, CycleStatus::Swept);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_noop!(
+            IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0),
+            Error::<Test>::CycleNotClosed
+        );
+    });
+}
+
+#[test]
+fn time_weighted_shares_favour_early_contributions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_share_mode(RuntimeOrigin::root(), ShareMode::TimeWeighted));
+        assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 170));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+        System::set_block_number(6);
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 100));
+
+        // 100 for 11 blocks against 100 for 6 blocks
+        assert_eq!(IkubTreasury::cycle_share_weight(CLUB, 0), 1_700);
+
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 170));
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_eq!(Balances::free_balance(ALICE), 1_010);
+        assert_eq!(Balances::free_balance(BOB), 960);
+    });
+}
+
+#[test]
+fn top_ups_are_weighted_as_separate_lots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::set_share_mode(RuntimeOrigin::root(), ShareMode::TimeWeighted));
+        assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 230));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 100));
+        System::set_block_number(11);
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+
+        let lots = IkubTreasury::contribution_lots((CLUB, 0, ALICE));
+        assert_eq!(
+            lots.iter().map(|lot| (lot.amount, lot.contributed_at)).collect::<Vec<_>>(),
+            vec![(100, 1), (100, 11)]
+        );
+        let contribution = IkubTreasury::contributions((CLUB, 0, ALICE)).unwrap();
+        assert_eq!((contribution.amount, contribution.contributed_at), (200, 1));
+
+        System::set_block_number(12);
+        assert_ok!(IkubTreasury::close_cycle(RuntimeOrigin::signed(ALICE), CLUB));
+        assert_ok!(IkubTreasury::distribute_returns(RuntimeOrigin::root(), 0, 230));
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(ALICE), CLUB, 0));
+        assert_ok!(IkubTreasury::claim_returns(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_eq!(Balances::free_balance(ALICE), 920);
+        assert_eq!(Balances::free_balance(BOB), 1_010);
+    });
+}
+
+#[test]
+fn contribution_lots_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        for _ in 0..3 {
+            assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 10));
+        }
+        assert_noop!(
+            IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 10),
+            Error::<Test>::TooManyContributionLots
+        );
+    });
+}
+
+fn unit_holders() {
+    assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+    assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+    assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 100));
+    assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(BOB), CLUB, 100));
+}
+
+#[test]
+fn contributions_mint_units_at_net_asset_value() {
+    new_test_ext().execute_with(|| {
+        unit_holders();
+
+        // The deposit doubled the value of ALICE's units before BOB contributed
+        assert_eq!(IkubTreasury::total_units(CLUB), 150);
+        assert_eq!(IkubTreasury::net_asset_value(CLUB), 300);
+        assert_eq!(IkubTreasury::member_equity(CLUB, &ALICE), MemberEquity { units: 100, value: 200 });
+        assert_eq!(IkubTreasury::member_equity(CLUB, &BOB), MemberEquity { units: 50, value: 100 });
+    });
+}
+
+fn approve_and_execute(withdrawal_id: u64) {
+    assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, withdrawal_id));
+    assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, withdrawal_id));
+    assert_ok!(IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, withdrawal_id));
+}
+
+#[test]
+fn redemptions_and_withdrawals_burn_units() {
+    new_test_ext().execute_with(|| {
+        unit_holders();
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 2));
+
+        assert_ok!(IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 50));
+        assert_eq!(IkubTreasury::units(CLUB, ALICE), 50);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 100);
+        assert_eq!(Balances::free_balance(ALICE), 900);
+        approve_and_execute(0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+
+        assert_ok!(IkubTreasury::withdraw_equity(RuntimeOrigin::signed(BOB), CLUB, 60));
+        approve_and_execute(1);
+        assert_eq!(Balances::free_balance(BOB), 960);
+        assert_eq!(IkubTreasury::units(CLUB, BOB), 20);
+        assert_noop!(
+            IkubTreasury::withdraw_equity(RuntimeOrigin::signed(BOB), CLUB, 100),
+            Error::<Test>::InsufficientUnits
+        );
+
+        assert_eq!(IkubTreasury::total_units(CLUB), 70);
+        assert_eq!(IkubTreasury::net_asset_value(CLUB), 140);
+
+        // Redeemed funds no longer earn returns from the open cycle
+        assert!(IkubTreasury::contributions((CLUB, 0, ALICE)).is_none());
+        assert_eq!(IkubTreasury::contributions((CLUB, 0, BOB)).unwrap().amount, 40);
+        assert_eq!(IkubTreasury::contribution_cycles(CLUB, 0).unwrap().total_contributions, 40);
+    });
+}
+
+#[test]
+fn dropped_redemptions_return_their_units() {
+    new_test_ext().execute_with(|| {
+        unit_holders();
+        assert_noop!(
+            IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 50),
+            Error::<Test>::NoSignerSet
+        );
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 2));
+
+        assert_ok!(IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 50));
+        assert_eq!(IkubTreasury::total_units(CLUB), 100);
+        assert_ok!(IkubTreasury::cancel_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0));
+
+        assert_eq!(IkubTreasury::units(CLUB, ALICE), 100);
+        assert_eq!(IkubTreasury::total_units(CLUB), 150);
+        assert_eq!(IkubTreasury::earmarked(CLUB), 0);
+        assert_eq!(IkubTreasury::redemptions(CLUB, 0), None);
+    });
+}
+
+#[test]
+fn redemptions_are_subject_to_spending_limits() {
+    new_test_ext().execute_with(|| {
+        unit_holders();
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 2));
+        assert_ok!(IkubTreasury::set_spending_limit(RuntimeOrigin::root(), 50, 100, 10));
+
+        assert_ok!(IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 50));
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(BOB), CLUB, 0));
+        assert_ok!(IkubTreasury::approve_withdrawal(RuntimeOrigin::signed(CHARLIE), CLUB, 0));
+        assert_noop!(
+            IkubTreasury::execute_withdrawal(RuntimeOrigin::signed(ALICE), CLUB, 0),
+            Error::<Test>::SpendingLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn positions_count_towards_net_asset_value() {
+    new_test_ext().execute_with(|| {
+        unit_holders();
+        assert_ok!(IkubTreasury::set_position_value(RuntimeOrigin::root(), 300));
+
+        assert_eq!(IkubTreasury::net_asset_value(CLUB), 600);
+        assert_eq!(IkubTreasury::member_equity(CLUB, &ALICE), MemberEquity { units: 100, value: 400 });
+
+        // Redemptions are priced at net asset value, but positions are not
+        // sold to pay them
+        assert_ok!(IkubTreasury::set_signers(RuntimeOrigin::root(), vec![ALICE, BOB, CHARLIE], 2));
+        assert_noop!(
+            IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 100),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_noop!(
+            IkubTreasury::withdraw_equity(RuntimeOrigin::signed(ALICE), CLUB, 400),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(IkubTreasury::redeem_units(RuntimeOrigin::signed(ALICE), CLUB, 50));
+        System::assert_last_event(
+            Event::<Test>::RedemptionRequested {
+                club_id: CLUB,
+                account: ALICE,
+                withdrawal_id: 0,
+                units: 50,
+                value: 200,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn assets_held_before_the_first_units_belong_to_the_club() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IkubTreasury::deposit(RuntimeOrigin::signed(CHARLIE), CLUB, 100));
+        assert_ok!(IkubTreasury::open_contribution_cycle(RuntimeOrigin::signed(ALICE), CLUB, Some(10), None));
+        assert_ok!(IkubTreasury::contribute(RuntimeOrigin::signed(ALICE), CLUB, 100));
+
+        let treasury = IkubTreasury::treasury_account_id(CLUB);
+        assert_eq!(IkubTreasury::units(CLUB, treasury), 100);
+        assert_eq!(IkubTreasury::member_equity(CLUB, &ALICE), MemberEquity { units: 100, value: 100 });
+    });
+}
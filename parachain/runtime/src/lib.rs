@@ -7,6 +7,7 @@ use frame_support::{
     construct_runtime, parameter_types,
     traits::{ConstU128, ConstU32, ConstU64, ConstU16, ConstU8, EitherOfDiverse, EqualPrivilege, Nothing, Get},
     weights::Weight,
+    PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::CurrencyAdapter;
@@ -37,7 +38,7 @@ pub const RUNTIME_API_VERSIONS: sp_version::RuntimeVersion = sp_version::Runtime
     spec_name: create_runtime_str!("ikubchain"),
     impl_name: create_runtime_str!("ikubchain"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 0,
     apis: sp_version::create_apis_vec!([]),
     transaction_version: 1,
@@ -50,7 +51,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("ikubchain"),
     impl_name: create_runtime_str!("ikubchain"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -133,6 +134,15 @@ parameter_types! {
     pub const MaxProposalsPerClub: u32 = 100;
     pub const MaxVotingDuration: u32 = 100000;
     pub const MinProposalDeposit: u128 = 1000;
+    pub const MaxCommentsPerProposal: u32 = 256;
+    pub const MaxProposalOptions: u32 = 16;
+    pub const GovernanceQuorum: u8 = 20;
+    pub const MaxGuardians: u32 = 7;
+    pub const MaxProposalsPerSession: u32 = 20;
+    pub const MaxSessionsPerBlock: u32 = 10;
+    pub const MaxNoteSize: u32 = 16 * 1024;
+    pub const NoteDepositBase: u128 = 1000;
+    pub const NoteDepositPerByte: u128 = 10;
 }
 
 impl pallet_ikub_governance::Config for Runtime {
@@ -141,6 +151,35 @@ impl pallet_ikub_governance::Config for Runtime {
     type MaxProposalsPerClub = MaxProposalsPerClub;
     type MaxVotingDuration = ConstU32<MaxVotingDuration>;
     type MinProposalDeposit = ConstU128<MinProposalDeposit>;
+    type Members = IkubMembers;
+    type MaxMembersPerClub = MaxMembersPerClub;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxProposalOptions = MaxProposalOptions;
+    type Quorum = GovernanceQuorum;
+    type Treasury = IkubTreasury;
+    type Spends = ClubSpends;
+    type ClubOrigin = pallet_ikub_governance::EnsureClub<Runtime>;
+    type MaxGuardians = MaxGuardians;
+    type MaxCommentsPerProposal = MaxCommentsPerProposal;
+    type MaxProposalsPerSession = MaxProposalsPerSession;
+    type MaxSessionsPerBlock = MaxSessionsPerBlock;
+    type MaxNoteSize = MaxNoteSize;
+    type NoteDepositBase = NoteDepositBase;
+    type NoteDepositPerByte = NoteDepositPerByte;
+}
+
+/// Club funds moved by calls that clubs dispatch through governance
+pub struct ClubSpends;
+
+impl pallet_ikub_governance::CallSpend<RuntimeCall, Balance> for ClubSpends {
+    fn spend_of(call: &RuntimeCall) -> Balance {
+        match call {
+            RuntimeCall::IkubTreasury(pallet_ikub_treasury::Call::distribute_returns { returns, .. }) => *returns,
+            RuntimeCall::IkubCrosschain(pallet_ikub_crosschain::Call::send_funds_to_parachain { amount, .. }) => *amount,
+            _ => 0,
+        }
+    }
 }
 
 parameter_types! {
@@ -148,6 +187,15 @@ parameter_types! {
     pub const MinSignatures: u32 = 3;
     pub const MinContribution: u128 = 1000;
     pub const DefaultContributionPeriod: u32 = 10000; // blocks
+    pub const TreasuryPalletId: PalletId = PalletId(*b"ikubtrsy");
+    pub const WithdrawalExpiry: u32 = 100800; // ~7 days at 6s blocks
+    pub const MaxExpiringWithdrawals: u32 = 50;
+    pub const MaxExpiringClubsPerBlock: u32 = 20;
+    pub const MaxRoscaParticipants: u32 = 100;
+    pub const ClaimPeriod: u32 = 432000; // ~30 days at 6s blocks
+    pub const MaxContributionLots: u32 = 20;
+    pub const MaxClosingsPerBlock: u32 = 20;
+    pub const PayoutWeightBudget: Weight = Weight::from_parts(100_000_000_000, 0); // 5% of the block
 }
 
 impl pallet_ikub_treasury::Config for Runtime {
@@ -157,22 +205,48 @@ impl pallet_ikub_treasury::Config for Runtime {
     type MinSignatures = MinSignatures;
     type MinContribution = ConstU128<MinContribution>;
     type DefaultContributionPeriod = ConstU32<DefaultContributionPeriod>;
+    type GovernanceOrigin = pallet_ikub_governance::EnsureClub<Runtime>;
+    type TreasuryPalletId = TreasuryPalletId;
+    type WithdrawalExpiry = WithdrawalExpiry;
+    type MaxExpiringWithdrawals = MaxExpiringWithdrawals;
+    type MaxExpiringClubsPerBlock = MaxExpiringClubsPerBlock;
+    type MaxRoscaParticipants = MaxRoscaParticipants;
+    type ClaimPeriod = ClaimPeriod;
+    type Members = IkubMembers;
+    type PayoutWeightBudget = PayoutWeightBudget;
+    type MaxContributionLots = MaxContributionLots;
+    type MaxClosingsPerBlock = MaxClosingsPerBlock;
+    type Randomness = RandomnessCollectiveFlip;
 }
 
 // For MVP, we'll simplify the crosschain config
 // In production, this would need proper XCM configuration
+parameter_types! {
+    pub const CrosschainPalletId: PalletId = PalletId(*b"ikub/xcm");
+}
+
 impl pallet_ikub_crosschain::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type Treasury = IkubTreasury;
+    type GovernanceOrigin = pallet_ikub_governance::EnsureClub<Runtime>;
+    type OperatorOrigin = frame_system::EnsureRoot<AccountId>;
+    type PalletId = CrosschainPalletId;
 }
 
 parameter_types! {
     pub const MaxMembersPerClub: u32 = 1000;
+    pub const InitialReputation: u64 = 100;
+    pub const MissedContributionPenalty: u64 = 10;
+    pub const MaxMissedContributions: u32 = 3;
 }
 
 impl pallet_ikub_members::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxMembersPerClub = MaxMembersPerClub;
+    type InitialReputation = InitialReputation;
+    type MissedContributionPenalty = MissedContributionPenalty;
+    type MaxMissedContributions = MaxMissedContributions;
 }
 
 impl pallet_ikub_disputes::Config for Runtime {
@@ -196,6 +270,8 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
@@ -273,6 +349,7 @@ construct_runtime!(
         IkubMembers: pallet_ikub_members,
         IkubDisputes: pallet_ikub_disputes,
         IkubAnalytics: pallet_ikub_analytics,
+        RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
     }
 );
 
@@ -357,6 +434,70 @@ sp_api::impl_runtime_apis! {
         }
     }
 
+    impl pallet_ikub_governance_runtime_api::GovernanceApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn proposals(
+            club_id: pallet_ikub_governance::ClubId,
+            status: Option<pallet_ikub_governance::ProposalStatus>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<pallet_ikub_governance::ProposalInfo<AccountId, Balance, BlockNumber>> {
+            IkubGovernance::proposals_of(club_id, status, offset, limit)
+        }
+
+        fn proposal(
+            club_id: pallet_ikub_governance::ClubId,
+            proposal_id: pallet_ikub_governance::ProposalId,
+        ) -> Option<pallet_ikub_governance::ProposalInfo<AccountId, Balance, BlockNumber>> {
+            IkubGovernance::proposal_details(club_id, proposal_id)
+        }
+
+        fn votes_of(
+            club_id: pallet_ikub_governance::ClubId,
+            voter: AccountId,
+        ) -> Vec<pallet_ikub_governance::VoteInfo<Balance, BlockNumber>> {
+            IkubGovernance::votes_by(club_id, &voter)
+        }
+
+        fn voting_power(
+            club_id: pallet_ikub_governance::ClubId,
+            proposal_id: pallet_ikub_governance::ProposalId,
+            who: AccountId,
+        ) -> Balance {
+            IkubGovernance::effective_voting_power(club_id, proposal_id, &who)
+        }
+
+        fn proposal_revisions(
+            club_id: pallet_ikub_governance::ClubId,
+            proposal_id: pallet_ikub_governance::ProposalId,
+        ) -> Vec<pallet_ikub_governance::ProposalRevision<AccountId, BlockNumber>> {
+            IkubGovernance::revisions_of(club_id, proposal_id)
+        }
+
+        fn proposal_comments(
+            club_id: pallet_ikub_governance::ClubId,
+            proposal_id: pallet_ikub_governance::ProposalId,
+        ) -> Vec<pallet_ikub_governance::ProposalComment<AccountId, BlockNumber>> {
+            IkubGovernance::comments_of(club_id, proposal_id)
+        }
+    }
+
+    impl pallet_ikub_treasury_runtime_api::TreasuryApi<Block, AccountId, Balance> for Runtime {
+        fn net_asset_value(club_id: pallet_ikub_treasury::ClubId) -> Balance {
+            IkubTreasury::net_asset_value(club_id)
+        }
+
+        fn total_units(club_id: pallet_ikub_treasury::ClubId) -> Balance {
+            IkubTreasury::total_units(club_id)
+        }
+
+        fn member_equity(
+            club_id: pallet_ikub_treasury::ClubId,
+            who: AccountId,
+        ) -> pallet_ikub_treasury::MemberEquity<Balance> {
+            IkubTreasury::member_equity(club_id, &who)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             // For MVP standalone mode, return empty collation info
@@ -379,8 +520,23 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
+/// Pending storage migrations, applied in order on runtime upgrade
+pub type Migrations = (
+    pallet_ikub_governance::migrations::v1::MigrateToV1<Runtime>,
+    pallet_ikub_disputes::migrations::v1::MigrateToV1<Runtime>,
+    pallet_ikub_members::migrations::v1::MigrateToV1<Runtime>,
+    pallet_ikub_governance::migrations::v2::MigrateToV2<Runtime>,
+    pallet_ikub_treasury::migrations::v1::MigrateToV1<Runtime>,
+    pallet_ikub_treasury::migrations::v2::MigrateToV2<Runtime>,
+    pallet_ikub_treasury::migrations::v3::MigrateToV3<Runtime>,
+    pallet_ikub_treasury::migrations::v4::MigrateToV4<Runtime>,
+    pallet_ikub_treasury::migrations::v5::MigrateToV5<Runtime>,
+    pallet_ikub_treasury::migrations::v6::MigrateToV6<Runtime>,
+);
+
 pub type AllPalletsWithSystem = (
     IkubGovernance,
     IkubTreasury,